@@ -0,0 +1,202 @@
+//! The known answers for our own puzzle inputs, kept in `data/answers.toml`
+//! so that every day can be re-checked after refactoring the shared modules.
+//!
+//! ```toml
+//! [05]
+//! part1 = 111627841
+//! part2 = 69323688
+//!
+//! [25]
+//! part1 = 543564
+//! ```
+use crate::solution::{Answer, Part};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AnswersError {
+    #[error("couldn't read {}: {source}", path.to_string_lossy())]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("couldn't parse the answers: {0}")]
+    Parse(#[from] toml::de::Error),
+    #[error("expected the day number as the table name, got: [{0}]")]
+    BadDay(String),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Integer(i64),
+    Text(String),
+}
+
+impl From<Expected> for Answer {
+    fn from(value: Expected) -> Self {
+        match value {
+            Expected::Integer(value) => value.into(),
+            Expected::Text(value) => value.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct DayAnswers {
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+}
+
+impl DayAnswers {
+    fn into_parts(self) -> impl Iterator<Item = (Part, Answer)> {
+        [(Part::One, self.part1), (Part::Two, self.part2)]
+            .into_iter()
+            .filter_map(|(part, expected)| expected.map(|expected| (part, expected.into())))
+    }
+}
+
+/// Parse the answers for a single day, i.e. just the `part1 = ...` and `part2 = ...` lines.
+pub fn parse_day(data: &str) -> Result<Vec<(Part, Answer)>, AnswersError> {
+    let answers: DayAnswers = toml::from_str(data)?;
+    Ok(answers.into_parts().collect())
+}
+
+/// Write the answers for a single day in the form that [parse_day] reads.
+pub fn render_day(answers: &[(Part, Answer)]) -> String {
+    answers
+        .iter()
+        .filter_map(|(part, answer)| match answer {
+            Answer::Integer(value) => Some(format!("part{part} = {value}\n")),
+            Answer::Text(value) => Some(format!(
+                "part{part} = {}\n",
+                toml::Value::String(value.clone())
+            )),
+            Answer::Unsolved => None,
+        })
+        .collect()
+}
+
+/// Compare the rendered forms so that a number stored as a string still matches.
+pub fn matches(expected: &Answer, actual: &Answer) -> bool {
+    expected.to_string() == actual.to_string()
+}
+
+/// The expected answer for every day and part that we've solved before.
+#[derive(Debug, Clone, Default)]
+pub struct Answers(BTreeMap<(u32, Part), Answer>);
+
+impl Answers {
+    pub fn parse(data: &str) -> Result<Self, AnswersError> {
+        let days: HashMap<String, DayAnswers> = toml::from_str(data)?;
+
+        let mut answers = BTreeMap::new();
+        for (day, parts) in days {
+            let Ok(number) = day.parse::<u32>() else {
+                return Err(AnswersError::BadDay(day));
+            };
+            for (part, expected) in parts.into_parts() {
+                answers.insert((number, part), expected);
+            }
+        }
+        Ok(Self(answers))
+    }
+
+    /// Read the answers from the given file. A missing file just means nothing is known yet.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match std::fs::read_to_string(path) {
+            Ok(data) => Self::parse(&data),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(AnswersError::Read {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&Answer> {
+        self.0.get(&(day, part))
+    }
+
+    pub fn check(&self, day: u32, part: Part, answer: &Answer) -> Status {
+        match self.get(day, part) {
+            None => Status::Missing,
+            Some(expected) if matches(expected, answer) => Status::Pass,
+            Some(_) => Status::Fail,
+        }
+    }
+}
+
+pub fn default_answers_path(data_dir: &Path) -> PathBuf {
+    data_dir.join("answers.toml")
+}
+
+/// How a computed answer compares against the stored one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// There's no stored answer (or no input) to check against.
+    Missing,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let answers = Answers::parse(
+            r#"
+[05]
+part1 = 35
+part2 = "46"
+
+[25]
+part1 = 54
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(answers.check(5, Part::One, &35u64.into()), Status::Pass);
+        assert_eq!(answers.check(5, Part::One, &36u64.into()), Status::Fail);
+        assert_eq!(answers.check(5, Part::Two, &46u64.into()), Status::Pass);
+        assert_eq!(
+            answers.check(25, Part::Two, &Answer::Unsolved),
+            Status::Missing
+        );
+        assert_eq!(
+            answers.check(7, Part::One, &6440u64.into()),
+            Status::Missing
+        );
+    }
+
+    #[test]
+    fn render_and_parse_day() {
+        let answers = vec![
+            (Part::One, Answer::Integer(35)),
+            (Part::Two, Answer::Text("jqt \"rhn\"".to_string())),
+        ];
+        assert_eq!(parse_day(&render_day(&answers)).unwrap(), answers);
+    }
+
+    #[test]
+    fn bad_day() {
+        assert!(matches!(
+            Answers::parse("[day05]\npart1 = 35"),
+            Err(AnswersError::BadDay(_))
+        ));
+    }
+}
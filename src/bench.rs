@@ -0,0 +1,331 @@
+//! Repeatedly run the parse and part stages of a day to get a feel
+//! for how long each of them takes, and compare that against an earlier
+//! run that was saved as a named baseline.
+use crate::days::Day;
+use crate::error::AocError;
+use crate::solution::{Answer, Part};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum BaselineError {
+    #[error("no baseline named {name:?} at {}; save one first with `--save {name}`", path.to_string_lossy())]
+    Missing { name: String, path: PathBuf },
+    #[error("couldn't access the baseline at {}: {source}", path.to_string_lossy())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("the baseline at {} is malformed: {source}", path.to_string_lossy())]
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+/// Summary statistics of the timings of a single stage, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        let mid = samples.len() / 2;
+        let median = match samples.len() % 2 {
+            0 => (samples[mid - 1] + samples[mid]) / 2,
+            _ => samples[mid],
+        };
+
+        Some(Self {
+            min_ns: samples[0].as_nanos() as u64,
+            median_ns: median.as_nanos() as u64,
+            max_ns: samples[samples.len() - 1].as_nanos() as u64,
+        })
+    }
+
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(self.min_ns)
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    pub fn max(&self) -> Duration {
+        Duration::from_nanos(self.max_ns)
+    }
+}
+
+/// The timings of every stage of a single day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: u32,
+    pub iterations: usize,
+    pub parse: Stats,
+    /// Parts that aren't solved yet have no timings.
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl DayBench {
+    pub fn part(&self, part: Part) -> Option<&Stats> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    pub fn stage(&self, stage: Stage) -> Option<&Stats> {
+        match stage {
+            Stage::Parse => Some(&self.parse),
+            Stage::Part(part) => self.part(part),
+        }
+    }
+
+    /// A line in the order of [`TSV_HEADER`] for every stage that has timings.
+    pub fn to_tsv(&self) -> Vec<String> {
+        Stage::all()
+            .into_iter()
+            .filter_map(|stage| {
+                let stats = self.stage(stage)?;
+                Some(format!(
+                    "{}\t{}\t{}\t{}\t{}",
+                    self.day, stage, stats.min_ns, stats.median_ns, stats.max_ns
+                ))
+            })
+            .collect()
+    }
+}
+
+pub const TSV_HEADER: &str = "day\tstage\tmin_ns\tmedian_ns\tmax_ns";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Stage {
+    pub fn all() -> [Stage; 3] {
+        [Stage::Parse, Stage::Part(Part::One), Stage::Part(Part::Two)]
+    }
+}
+
+impl std::fmt::Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part(part) => f.pad(&format!("part{part}")),
+        }
+    }
+}
+
+/// Parse the input and solve both parts of the given day `iterations` times.
+/// Fails if the input can't be parsed.
+pub fn bench(day: &Day, data: &str, iterations: usize) -> Result<DayBench, AocError> {
+    let iterations = iterations.max(1);
+
+    let mut parse = vec![];
+    let mut parts = [vec![], vec![]];
+
+    for _ in 0..iterations {
+        let run = (day.run)(data, &Part::both())?;
+        parse.push(run.parse);
+        for part_run in run.parts {
+            if part_run.answer != Answer::Unsolved {
+                parts[part_run.part.number() as usize - 1].push(part_run.elapsed);
+            }
+        }
+    }
+
+    Ok(DayBench {
+        day: day.number,
+        iterations,
+        parse: Stats::from_samples(&parse).unwrap(),
+        part1: Stats::from_samples(&parts[0]),
+        part2: Stats::from_samples(&parts[1]),
+    })
+}
+
+/// Where the named baselines are kept: they only make sense on the machine they were recorded on.
+pub fn default_baseline_dir() -> PathBuf {
+    Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap_or(".".to_string()))
+        .join("target/aoc-baselines/")
+}
+
+pub fn baseline_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{name}.json"))
+}
+
+/// Save the given timings under a name, replacing any earlier baseline with that name.
+pub fn save_baseline(
+    dir: &Path,
+    name: &str,
+    benches: &[DayBench],
+) -> Result<PathBuf, BaselineError> {
+    let path = baseline_path(dir, name);
+    let io_err = |source| BaselineError::Io {
+        path: path.clone(),
+        source,
+    };
+
+    std::fs::create_dir_all(dir).map_err(io_err)?;
+    let json = serde_json::to_string_pretty(benches).map_err(|source| BaselineError::Json {
+        path: path.clone(),
+        source,
+    })?;
+    std::fs::write(&path, json).map_err(io_err)?;
+    Ok(path)
+}
+
+pub fn load_baseline(dir: &Path, name: &str) -> Result<Vec<DayBench>, BaselineError> {
+    let path = baseline_path(dir, name);
+    let json = std::fs::read_to_string(&path).map_err(|source| match source.kind() {
+        std::io::ErrorKind::NotFound => BaselineError::Missing {
+            name: name.to_string(),
+            path: path.clone(),
+        },
+        _ => BaselineError::Io {
+            path: path.clone(),
+            source,
+        },
+    })?;
+    serde_json::from_str(&json).map_err(|source| BaselineError::Json { path, source })
+}
+
+/// How the median time of a single stage changed compared to the baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub day: u32,
+    pub stage: Stage,
+    pub baseline: Stats,
+    pub current: Stats,
+    /// The relative change of the median, in percent. Positive means slower.
+    pub change: f64,
+    /// Whether the stage got slower by more than the threshold.
+    pub regressed: bool,
+}
+
+/// Compare the medians of every stage that was timed in both runs.
+/// A stage regressed if it got slower by more than `threshold` percent.
+pub fn compare(baseline: &[DayBench], current: &[DayBench], threshold: f64) -> Vec<Comparison> {
+    let mut comparisons = vec![];
+
+    for bench in current {
+        let Some(old) = baseline.iter().find(|old| old.day == bench.day) else {
+            continue;
+        };
+        for stage in Stage::all() {
+            let (Some(&before), Some(&after)) = (old.stage(stage), bench.stage(stage)) else {
+                continue;
+            };
+            let change = match before.median_ns {
+                0 => 0.,
+                median => (after.median_ns as f64 - median as f64) / median as f64 * 100.,
+            };
+            comparisons.push(Comparison {
+                day: bench.day,
+                stage,
+                baseline: before,
+                current: after,
+                change,
+                regressed: change > threshold,
+            });
+        }
+    }
+
+    comparisons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_nanos);
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(
+            stats,
+            Stats {
+                min_ns: 1,
+                median_ns: 2,
+                max_ns: 4
+            }
+        );
+
+        let samples = [5, 1, 3].map(Duration::from_nanos);
+        assert_eq!(Stats::from_samples(&samples).unwrap().median_ns, 3);
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    fn day_bench(day: u32, parse_ns: u64, part1_ns: Option<u64>) -> DayBench {
+        let stats = |ns| Stats {
+            min_ns: ns,
+            median_ns: ns,
+            max_ns: ns,
+        };
+        DayBench {
+            day,
+            iterations: 1,
+            parse: stats(parse_ns),
+            part1: part1_ns.map(stats),
+            part2: None,
+        }
+    }
+
+    #[test]
+    fn regressions() {
+        let baseline = [day_bench(5, 100, Some(1000)), day_bench(6, 100, None)];
+        let current = [
+            day_bench(5, 105, Some(1500)),
+            day_bench(6, 100, Some(10)),
+            day_bench(7, 100, None),
+        ];
+
+        assert_eq!(
+            current[0].to_tsv(),
+            vec!["5\tparse\t105\t105\t105", "5\tpart1\t1500\t1500\t1500"]
+        );
+
+        let comparisons = compare(&baseline, &current, 10.);
+        let summary = comparisons
+            .iter()
+            .map(|c| (c.day, c.stage, c.change.round() as i64, c.regressed))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            summary,
+            vec![
+                (5, Stage::Parse, 5, false),
+                (5, Stage::Part(Part::One), 50, true),
+                (6, Stage::Parse, 0, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn save_and_load_baseline() {
+        let dir = TempDir::new("save_and_load_baseline");
+        let benches = vec![day_bench(5, 100, Some(1000))];
+
+        save_baseline(dir.path(), "main", &benches).unwrap();
+        assert_eq!(load_baseline(dir.path(), "main").unwrap(), benches);
+        assert!(matches!(
+            load_baseline(dir.path(), "nope"),
+            Err(BaselineError::Missing { .. })
+        ));
+    }
+}
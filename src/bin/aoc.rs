@@ -0,0 +1,643 @@
+use aoc_2023::answers::{self, Answers, Status};
+use aoc_2023::bench::{self, Comparison, DayBench, Stage};
+use aoc_2023::days::{self, Day};
+use aoc_2023::input::{self, InputSource};
+use aoc_2023::report::{self, Format, Record, RecordStatus};
+use aoc_2023::solution::Part;
+use clap::{Parser, Subcommand};
+use std::{path::PathBuf, str::FromStr, time::Duration};
+
+#[derive(Debug, Clone, Parser)]
+#[command(about = "Run the Advent of Code 2023 solutions")]
+pub struct Opts {
+    /// Directory that holds the `<day>.in` puzzle inputs.
+    #[arg(short, long, global = true, default_value = None)]
+    data_dir: Option<PathBuf>,
+    /// Log more of what the solutions are doing: `-v` for info, `-vv` for debug output and `-vvv` for everything.
+    /// `RUST_LOG` picks levels for single days, e.g. `RUST_LOG=aoc_2023::days::day23=debug`.
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    /// Log only errors, or nothing at all with `-qq`.
+    #[arg(short, long, global = true, action = clap::ArgAction::Count, conflicts_with = "verbose")]
+    quiet: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum Command {
+    /// Solve one day, or every registered day with `all`.
+    Run {
+        days: Selection,
+        /// Only solve this part of the puzzle.
+        #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+        /// Read the input from this file (or `-` for stdin) instead of `<data-dir>/<day>.in`.
+        /// Only allowed when solving a single day.
+        #[arg(short, long)]
+        input: Option<InputSource>,
+        /// How to print the results: `text`, `json` or `tsv`.
+        /// Anything that isn't a result, like errors, goes to stderr.
+        #[arg(short, long, default_value = "text")]
+        format: Format,
+    },
+    /// List the days compiled into this build.
+    List,
+    /// Solve every registered day and check the answers against the stored ones.
+    Verify {
+        /// The file with the known answers. Defaults to `<data-dir>/answers.toml`.
+        #[arg(short, long)]
+        answers: Option<PathBuf>,
+    },
+    /// Time the parse and part stages of one day, or every registered day with `all`.
+    Bench {
+        days: Selection,
+        /// How many times to run every stage.
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+        /// How to print the timings: `text`, `json` or `tsv`.
+        #[arg(short, long, default_value = "text")]
+        format: Format,
+        /// Save the timings as a baseline with this name.
+        #[arg(long)]
+        save: Option<String>,
+        /// Compare the timings against the baseline with this name.
+        #[arg(long)]
+        baseline: Option<String>,
+        /// Flag a stage as a regression when its median got slower by more than this many percent.
+        #[arg(long, default_value_t = 10.)]
+        threshold: f64,
+    },
+    /// Set up a new day: its module with an example test, its feature and its spot in the registry.
+    NewDay {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+        /// The root of the crate to add the day to.
+        #[arg(long)]
+        root: Option<PathBuf>,
+    },
+    /// Solve a part of a day and submit the answer, unless it's already known to be wrong.
+    #[cfg(feature = "submit")]
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+        #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+        /// The file that records every submission. Defaults to `<data-dir>/submissions.json`.
+        #[arg(long)]
+        ledger: Option<PathBuf>,
+        #[command(flatten)]
+        client: aoc_2023::client::ClientConfig,
+    },
+    /// Show the standings, the rankings of every day and the points per day of a private leaderboard.
+    #[cfg(feature = "leaderboard")]
+    Leaderboard {
+        /// The id of the leaderboard, i.e. the number at the end of its URL.
+        id: u64,
+        /// Only show the rankings of this day.
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: Option<u32>,
+        #[command(flatten)]
+        client: aoc_2023::client::ClientConfig,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Selection {
+    All,
+    Day(u32),
+}
+
+impl Selection {
+    /// The registered days that were selected.
+    pub fn days(&self) -> Result<Vec<Day>, String> {
+        match *self {
+            Selection::All => Ok(days::registry()),
+            Selection::Day(number) => days::find(number)
+                .map(|day| vec![day])
+                .ok_or(format!("Day {number:02} is not registered in this build.")),
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(Selection::All);
+        }
+        match s.parse::<u32>() {
+            Ok(day @ 1..=25) => Ok(Selection::Day(day)),
+            _ => Err(format!(
+                "expected a day between 1 and 25, or `all`, got: {s}"
+            )),
+        }
+    }
+}
+
+/// Solve the given parts of a day, reporting why it couldn't be solved on stderr.
+pub fn run_day(
+    day: &Day,
+    source: &InputSource,
+    data_dir: &std::path::Path,
+    parts: &[Part],
+) -> Vec<Record> {
+    let run = input::load(day.number, source, data_dir)
+        .map_err(|err| err.to_string())
+        .and_then(|data| (day.run)(&data, parts).map_err(|err| err.to_string()));
+    if let Err(err) = &run {
+        eprintln!("Day {:02}: {err}", day.number);
+    }
+    report::records(day.number, parts, run.as_ref().ok())
+}
+
+/// Print the records of a single day for people to read.
+pub fn print_text(records: &[Record]) {
+    if let Some(record) = records.first() {
+        if let Some(parse_ns) = record.parse_ns {
+            println!(
+                "Day {:02} parse: ({:.2?})",
+                record.day,
+                Duration::from_nanos(parse_ns)
+            );
+        }
+    }
+    for record in records {
+        match (record.status, &record.answer, record.elapsed_ns) {
+            (RecordStatus::Solved, Some(answer), Some(elapsed_ns)) => println!(
+                "Day {:02} part {}: {} ({:.2?})",
+                record.day,
+                record.part,
+                answer,
+                Duration::from_nanos(elapsed_ns)
+            ),
+            (RecordStatus::Unsolved, _, _) => {
+                eprintln!("Day {:02} part {}: not solved yet", record.day, record.part)
+            }
+            // Already reported when the day failed.
+            _ => {}
+        }
+    }
+}
+
+/// Print a table with the status of every part of every registered day,
+/// and return whether none of them disagreed with the stored answers.
+pub fn verify(data_dir: &std::path::Path, answers: &Answers) -> bool {
+    println!(
+        "{:<4} {:<5} {:<8} {:<20} {:<20} {:>10}",
+        "Day", "Part", "Status", "Answer", "Expected", "Time"
+    );
+
+    let mut counts = [0; 3];
+    for day in days::registry() {
+        // A missing input can't be checked, but one that can't be parsed is a failure.
+        let run = input::load(day.number, &InputSource::DataDir, data_dir)
+            .map_err(|err| (Status::Missing, "no input", err.to_string()))
+            .and_then(|data| {
+                (day.run)(&data, &Part::both())
+                    .map_err(|err| (Status::Fail, "bad input", err.to_string()))
+            });
+        let runs = match run {
+            Ok(run) => run
+                .parts
+                .into_iter()
+                .map(|part_run| {
+                    let status = answers.check(day.number, part_run.part, &part_run.answer);
+                    (
+                        part_run.part,
+                        status,
+                        part_run.answer.to_string(),
+                        format!("{:.2?}", part_run.elapsed),
+                    )
+                })
+                .collect::<Vec<_>>(),
+            Err((status, answer, err)) => {
+                eprintln!("Day {:02}: {err}", day.number);
+                Part::both()
+                    .into_iter()
+                    .map(|part| (part, status, answer.to_string(), "-".to_string()))
+                    .collect()
+            }
+        };
+
+        for (part, status, answer, elapsed) in runs {
+            let expected = answers
+                .get(day.number, part)
+                .map(|expected| expected.to_string())
+                .unwrap_or("-".to_string());
+            counts[status as usize] += 1;
+            println!(
+                "{:<4} {:<5} {:<8} {:<20} {:<20} {:>10}",
+                format!("{:02}", day.number),
+                part.number(),
+                status,
+                answer,
+                expected,
+                elapsed
+            );
+        }
+    }
+
+    let [passed, failed, missing] = counts;
+    println!("\n{passed} passed, {failed} failed, {missing} missing");
+    failed == 0
+}
+
+fn registered_or_exit(selection: Selection) -> Vec<Day> {
+    selection.days().unwrap_or_else(|err| {
+        eprintln!("{err}");
+        std::process::exit(1);
+    })
+}
+
+/// Benchmark the given days against their inputs in the data directory,
+/// skipping (and reporting) the days that have no input.
+pub fn bench_days(days: &[Day], data_dir: &std::path::Path, iterations: usize) -> Vec<DayBench> {
+    days.iter()
+        .filter_map(
+            |day| match input::load(day.number, &InputSource::DataDir, data_dir) {
+                Ok(data) => {
+                    eprintln!("Benchmarking day {:02}...", day.number);
+                    bench::bench(day, &data, iterations)
+                        .map_err(|err| eprintln!("Day {:02}: {err}", day.number))
+                        .ok()
+                }
+                Err(err) => {
+                    eprintln!("Day {:02}: {err}", day.number);
+                    None
+                }
+            },
+        )
+        .collect()
+}
+
+pub fn print_bench_table(benches: &[DayBench]) {
+    println!(
+        "{:<4} {:<6} {:>12} {:>12} {:>12}",
+        "Day", "Stage", "Min", "Median", "Max"
+    );
+    for bench in benches {
+        for stage in Stage::all() {
+            let Some(stats) = bench.stage(stage) else {
+                continue;
+            };
+            println!(
+                "{:<4} {:<6} {:>12} {:>12} {:>12}",
+                format!("{:02}", bench.day),
+                stage,
+                format!("{:.2?}", stats.min()),
+                format!("{:.2?}", stats.median()),
+                format!("{:.2?}", stats.max())
+            );
+        }
+    }
+}
+
+/// Print how every stage changed compared to the baseline,
+/// and return whether none of them regressed.
+pub fn print_comparison_table(comparisons: &[Comparison], threshold: f64) -> bool {
+    println!(
+        "{:<4} {:<6} {:>12} {:>12} {:>9}  Status",
+        "Day", "Stage", "Baseline", "Current", "Change"
+    );
+    for comparison in comparisons {
+        println!(
+            "{:<4} {:<6} {:>12} {:>12} {:>8.1}%  {}",
+            format!("{:02}", comparison.day),
+            comparison.stage,
+            format!("{:.2?}", comparison.baseline.median()),
+            format!("{:.2?}", comparison.current.median()),
+            comparison.change,
+            if comparison.regressed {
+                "REGRESSED"
+            } else {
+                "ok"
+            }
+        );
+    }
+
+    let regressions = comparisons.iter().filter(|c| c.regressed).count();
+    println!("\n{regressions} stage(s) got more than {threshold}% slower");
+    regressions == 0
+}
+
+/// Solve the given part of a day, submit the answer and record the outcome in the ledger.
+/// Returns whether the answer was correct.
+#[cfg(feature = "submit")]
+pub fn submit_day(
+    config: &aoc_2023::client::ClientConfig,
+    day: &Day,
+    part: Part,
+    data_dir: &std::path::Path,
+    ledger_path: &std::path::Path,
+) -> bool {
+    use aoc_2023::solution::Answer;
+    use aoc_2023::submit::{self, Ledger, SubmitOutcome};
+
+    let data = match input::load(day.number, &InputSource::DataDir, data_dir) {
+        Ok(data) => data,
+        Err(err) => {
+            eprintln!("Day {:02}: {err}", day.number);
+            return false;
+        }
+    };
+    let answer = match (day.run)(&data, &[part]) {
+        Ok(mut run) => run.parts.remove(0).answer,
+        Err(err) => {
+            eprintln!("Day {:02}: {err}", day.number);
+            return false;
+        }
+    };
+    if answer == Answer::Unsolved {
+        eprintln!("Day {:02} part {part}: not solved yet", day.number);
+        return false;
+    }
+
+    let mut ledger = match Ledger::load(ledger_path) {
+        Ok(ledger) => ledger,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+    println!("Day {:02} part {part}: {answer}", day.number);
+    let outcome = submit::submit_with_ledger(
+        config,
+        &mut ledger,
+        day.number,
+        part.number(),
+        &answer.to_string(),
+    );
+    if let Err(err) = ledger.save(ledger_path) {
+        eprintln!("{err}");
+    }
+
+    match outcome {
+        Ok(SubmitOutcome::Correct) => {
+            println!("That's the right answer!");
+            true
+        }
+        Ok(outcome) => {
+            println!("{outcome:?}");
+            false
+        }
+        Err(err) => {
+            eprintln!("Day {:02} part {part}: {err}", day.number);
+            false
+        }
+    }
+}
+
+/// Hours, minutes and seconds, e.g. how long a star took after the puzzle unlocked.
+#[cfg(feature = "leaderboard")]
+fn format_elapsed(elapsed: std::time::Duration) -> String {
+    let secs = elapsed.as_secs();
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+#[cfg(feature = "leaderboard")]
+pub fn print_leaderboard(
+    leaderboard: &aoc_2023::leaderboard::Leaderboard,
+    day: Option<u32>,
+) -> Result<(), aoc_2023::leaderboard::LeaderboardError> {
+    let name = |id: &u64| leaderboard.members[id].display_name();
+    let width = leaderboard
+        .members
+        .values()
+        .map(|member| member.display_name().len())
+        .max()
+        .unwrap_or(0)
+        .max("name".len());
+
+    println!("{:>4}  {:>5}  {:>5}  name", "rank", "score", "stars");
+    for (rank, member) in leaderboard.standings().iter().enumerate() {
+        println!(
+            "{:>4}  {:>5}  {:>5}  {}",
+            rank + 1,
+            member.local_score,
+            member.stars,
+            member.display_name()
+        );
+    }
+
+    let days = match day {
+        Some(day) => vec![day],
+        None => leaderboard.days(),
+    };
+    for &day in &days {
+        for part in Part::both() {
+            let rankings = leaderboard.rankings(day, part)?;
+            if rankings.is_empty() {
+                continue;
+            }
+            println!("\nDay {day:02} part {part}");
+            println!("{:>4}  {:>10}  {:>6}  name", "rank", "time", "points");
+            for ranking in rankings {
+                println!(
+                    "{:>4}  {:>10}  {:>6}  {}",
+                    ranking.rank,
+                    format_elapsed(ranking.elapsed),
+                    ranking.points,
+                    name(&ranking.member)
+                );
+            }
+        }
+    }
+
+    let deltas = leaderboard.score_deltas()?;
+    println!("\nPoints per day");
+    print!("{:<width$}", "name");
+    for day in &days {
+        print!("  {:>4}", format!("{day:02}"));
+    }
+    println!("  {:>5}", "total");
+    for member in leaderboard.standings() {
+        let points = &deltas[&member.id];
+        print!("{:<width$}", member.display_name());
+        for day in &days {
+            print!("  {:>4}", points.get(day).copied().unwrap_or(0));
+        }
+        println!("  {:>5}", points.values().sum::<u64>());
+    }
+    Ok(())
+}
+
+/// Send the logs to stderr, starting from warnings and moving up or down a level for every `-v` or `-q`.
+fn init_logging(verbose: u8, quiet: u8) {
+    use log::LevelFilter;
+
+    let level = match verbose as i16 - quiet as i16 {
+        i16::MIN..=-2 => LevelFilter::Off,
+        -1 => LevelFilter::Error,
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    env_logger::Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .format_timestamp(None)
+        .init();
+}
+
+pub fn main() {
+    let opts = Opts::parse();
+    init_logging(opts.verbose, opts.quiet);
+    let data_dir = opts.data_dir.unwrap_or_else(input::default_data_dir);
+
+    match opts.command {
+        Command::List => {
+            for day in days::registry() {
+                println!("Day {:02}", day.number);
+            }
+        }
+        #[cfg(feature = "submit")]
+        Command::Submit {
+            day,
+            part,
+            ledger,
+            client,
+        } => {
+            let day = registered_or_exit(Selection::Day(day)).remove(0);
+            let part = Part::try_from(part).unwrap();
+            let ledger_path =
+                ledger.unwrap_or_else(|| aoc_2023::submit::ledger::default_ledger_path(&data_dir));
+            if !submit_day(&client, &day, part, &data_dir, &ledger_path) {
+                std::process::exit(1);
+            }
+        }
+        #[cfg(feature = "leaderboard")]
+        Command::Leaderboard { id, day, client } => {
+            let printed = aoc_2023::leaderboard::fetch(&client, id)
+                .and_then(|leaderboard| print_leaderboard(&leaderboard, day));
+            if let Err(err) = printed {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        Command::NewDay { day, root } => {
+            let root = root.unwrap_or_else(aoc_2023::scaffold::default_root);
+            match aoc_2023::scaffold::scaffold(&root, day) {
+                Ok(written) => {
+                    for path in written {
+                        println!("Wrote {}", path.to_string_lossy());
+                    }
+                    println!("Paste the example into the test of day{day:02}.rs and `cargo run -- run {day}` once it's solved.");
+                }
+                Err(err) => {
+                    eprintln!("{err}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Verify { answers } => {
+            let path = answers.unwrap_or_else(|| answers::default_answers_path(&data_dir));
+            let answers = match Answers::load(&path) {
+                Ok(answers) => answers,
+                Err(err) => {
+                    eprintln!("{err}");
+                    std::process::exit(1);
+                }
+            };
+            if !verify(&data_dir, &answers) {
+                std::process::exit(1);
+            }
+        }
+        Command::Bench {
+            days,
+            iterations,
+            format,
+            save,
+            baseline,
+            threshold,
+        } => {
+            let selected = registered_or_exit(days);
+            let baseline_dir = bench::default_baseline_dir();
+
+            // Fail early instead of after a long benchmark.
+            let baseline = baseline.map(|name| {
+                bench::load_baseline(&baseline_dir, &name).unwrap_or_else(|err| {
+                    eprintln!("{err}");
+                    std::process::exit(1);
+                })
+            });
+
+            let benches = bench_days(&selected, &data_dir, iterations);
+            match format {
+                Format::Text => print_bench_table(&benches),
+                Format::Json => println!("{}", serde_json::to_string_pretty(&benches).unwrap()),
+                Format::Tsv => {
+                    println!("{}", bench::TSV_HEADER);
+                    for line in benches.iter().flat_map(DayBench::to_tsv) {
+                        println!("{line}");
+                    }
+                }
+            }
+
+            if let Some(name) = save {
+                match bench::save_baseline(&baseline_dir, &name, &benches) {
+                    Ok(path) => eprintln!("Saved baseline {name:?} to {}", path.to_string_lossy()),
+                    Err(err) => {
+                        eprintln!("{err}");
+                        std::process::exit(1);
+                    }
+                }
+            }
+
+            if let Some(baseline) = baseline {
+                let comparisons = bench::compare(&baseline, &benches, threshold);
+                if !print_comparison_table(&comparisons, threshold) {
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Run {
+            days,
+            part,
+            input,
+            format,
+        } => {
+            if input.is_some() && matches!(days, Selection::All) {
+                eprintln!("--input can only be used when solving a single day.");
+                std::process::exit(1);
+            }
+            let source = input.unwrap_or_default();
+            let parts = match part {
+                Some(part) => vec![Part::try_from(part).unwrap()],
+                None => Part::both().to_vec(),
+            };
+
+            let selected = registered_or_exit(days);
+
+            if format == Format::Tsv {
+                println!("{}", report::TSV_HEADER);
+            }
+            let mut all_records = vec![];
+            for day in selected.iter() {
+                let records = run_day(day, &source, &data_dir, &parts);
+                match format {
+                    Format::Text => print_text(&records),
+                    Format::Tsv => {
+                        for record in &records {
+                            println!("{}", record.to_tsv());
+                        }
+                    }
+                    // A single document, so it can only be printed once everything ran.
+                    Format::Json => {}
+                }
+                all_records.extend(records);
+            }
+            if format == Format::Json {
+                println!("{}", serde_json::to_string_pretty(&all_records).unwrap());
+            }
+
+            if all_records
+                .iter()
+                .any(|record| record.status == RecordStatus::Error)
+            {
+                std::process::exit(1);
+            }
+        }
+    }
+}
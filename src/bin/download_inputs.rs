@@ -1,129 +1,448 @@
+use aoc_2023::client::ClientConfig;
+use aoc_2023::examples::{self, FixtureError};
+use aoc_2023::input;
+use aoc_2023::puzzle;
+use aoc_2023::unlock;
 use bytes::Bytes;
-use chrono::{Datelike, Utc};
+use chrono::Utc;
 use clap::Parser;
-use std::{env::var, path::PathBuf};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::Semaphore;
+
+/// How long to wait before the first retry; every retry after that waits twice as long.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// How long to wait past the unlock before asking for the input.
+const UNLOCK_GRACE: Duration = Duration::from_secs(2);
 
 #[derive(Debug, Clone, Parser)]
 pub struct Opts {
-    #[arg(short, long, env = "AOC_SESSION")]
-    aoc_session: Option<String>,
-    #[arg(short, long, default_value = None)]
-    day: Option<usize>,
+    #[command(flatten)]
+    client: ClientConfig,
+    /// Only download this day. Defaults to every day that's out for the year.
+    #[arg(long, default_value = None)]
+    day: Option<u32>,
     #[arg(short, long, default_value = None)]
     data_dir: Option<PathBuf>,
+    /// Also extract the examples from the puzzle description into `<data-dir>/examples/`.
+    #[arg(long)]
+    examples: bool,
+    /// Download the inputs again even if they're already on disk.
+    #[arg(short, long)]
+    force: bool,
+    /// How many times to retry a request that failed for a reason that may go away.
+    #[arg(long, default_value_t = 3)]
+    retries: u32,
+    /// How many days to download at the same time.
+    #[arg(short, long, default_value_t = 4)]
+    jobs: usize,
+    /// Then wait for the next puzzle (or the given `--day`) to unlock and download it right away.
+    #[arg(short, long)]
+    wait: bool,
 }
 
-pub async fn download_inputs(
-    day: u32,
-    client: &reqwest::Client,
-    session: &str,
-) -> Result<bytes::Bytes, String> {
-    let url = format!("https://adventofcode.com/2023/day/{day}/input");
+#[derive(Debug, Error)]
+pub enum DownloadError {
+    #[error("{0}")]
+    Config(String),
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("the server responded with {status}{}", with_colon(message))]
+    Status {
+        status: reqwest::StatusCode,
+        message: String,
+    },
+    #[error("couldn't write {}: {source}", path.to_string_lossy())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error(transparent)]
+    Examples(#[from] FixtureError),
+    #[error("the download panicked: {0}")]
+    Task(#[from] tokio::task::JoinError),
+}
 
-    let Ok(resp) = client
-        .get(&url)
-        .header("Cookie", format!("session={session}"))
-        .send()
-        .await
-    else {
-        return Err(format!("failed to download input for day: {}", day));
-    };
+fn with_colon(message: &str) -> String {
+    match message.is_empty() {
+        true => String::new(),
+        false => format!(": {message}"),
+    }
+}
 
-    if resp.status() != 200 {
-        eprintln!("{}", resp.text_with_charset("utf-8").await.unwrap());
-        return Err(format!(
-            "could not get a successful response for day: {}",
-            day
-        ));
+impl DownloadError {
+    /// Whether trying again later might succeed, e.g. a timeout or an overloaded server,
+    /// as opposed to a missing session or a puzzle that isn't out yet.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            DownloadError::Request(_) => true,
+            DownloadError::Status { status, .. } => {
+                status.is_server_error() || *status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            }
+            _ => false,
+        }
     }
+}
 
-    resp.bytes().await.map_err(|err| err.to_string())
+/// What happened to a single day.
+#[derive(Debug)]
+pub enum Outcome {
+    Downloaded,
+    /// Everything that was asked for is already on disk.
+    Skipped,
+    Failed(DownloadError),
 }
 
-pub async fn save_inputs<P>(day: u32, contents: Bytes, root_dir: P) -> std::io::Result<()>
-where
-    P: AsRef<std::path::Path>,
-{
-    let root_dir = root_dir.as_ref();
-    let filepath = root_dir.join(format!("{:02}.in", day));
-    let res = tokio::fs::write(filepath.clone(), contents).await;
-    if res.is_ok() {
-        eprintln!(
-            "Downloaded inputs for day {day:02} to: {}",
-            filepath.to_string_lossy()
-        );
+async fn get(
+    client: &reqwest::Client,
+    config: &ClientConfig,
+    url: &str,
+) -> Result<reqwest::Response, DownloadError> {
+    let resp = client
+        .get(url)
+        .header("Cookie", config.cookie().map_err(DownloadError::Config)?)
+        .send()
+        .await?;
+
+    let status = resp.status();
+    if status != 200 {
+        let body = resp.text().await.unwrap_or_default();
+        return Err(DownloadError::Status {
+            status,
+            message: body.lines().next().unwrap_or_default().trim().to_string(),
+        });
     }
-    res
+    Ok(resp)
 }
 
-pub async fn extract_input<P>(
-    date: u32,
-    client: reqwest::Client,
-    root_dir: P,
-    session: String,
-) -> std::result::Result<(), String>
+/// Keep trying until the attempt succeeds, fails for good, or we run out of retries.
+async fn with_retries<T, F, Fut>(day: u32, retries: u32, mut attempt: F) -> Result<T, DownloadError>
 where
-    P: AsRef<std::path::Path>,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, DownloadError>>,
 {
-    let contents = download_inputs(date, &client, &session).await?;
-    save_inputs(date, contents, root_dir)
+    let mut backoff = INITIAL_BACKOFF;
+    let mut retried = 0;
+    loop {
+        match attempt().await {
+            Err(err) if err.is_transient() && retried < retries => {
+                retried += 1;
+                eprintln!(
+                    "Day {day:02}: {err}; retrying in {}s ({retried}/{retries})",
+                    backoff.as_secs()
+                );
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+            result => return result,
+        }
+    }
+}
+
+pub async fn download_inputs(
+    day: u32,
+    client: &reqwest::Client,
+    config: &ClientConfig,
+) -> Result<Bytes, DownloadError> {
+    Ok(get(client, config, &config.input_url(day))
+        .await?
+        .bytes()
+        .await?)
+}
+
+/// Write the input next to where it belongs first, so that an interrupted
+/// download never leaves a truncated input behind to be skipped next time.
+pub async fn save_inputs(day: u32, contents: Bytes, root_dir: &Path) -> Result<(), DownloadError> {
+    let filepath = input::input_path(root_dir, day);
+    let partial = filepath.with_extension("in.part");
+    tokio::fs::create_dir_all(root_dir)
         .await
-        .map_err(|err| err.to_string())
+        .map_err(io_error(root_dir))?;
+    tokio::fs::write(&partial, contents)
+        .await
+        .map_err(io_error(&partial))?;
+    tokio::fs::rename(&partial, &filepath)
+        .await
+        .map_err(io_error(&filepath))?;
+
+    eprintln!(
+        "Downloaded inputs for day {day:02} to: {}",
+        filepath.to_string_lossy()
+    );
+    Ok(())
+}
+
+/// Fetch the puzzle description, which includes the second part once the first one is solved.
+pub async fn download_page(
+    day: u32,
+    client: &reqwest::Client,
+    config: &ClientConfig,
+) -> Result<String, DownloadError> {
+    Ok(get(client, config, &config.day_url(day))
+        .await?
+        .text()
+        .await?)
 }
 
-pub async fn extract_inputs<P>(
+fn io_error(path: &Path) -> impl FnOnce(std::io::Error) -> DownloadError {
+    let path = path.to_path_buf();
+    move |source| DownloadError::Io { path, source }
+}
+
+/// Keep the puzzle description around as Markdown, to read it offline.
+pub async fn save_puzzle(
+    day: u32,
+    html: &str,
+    root_dir: &Path,
+    config: &ClientConfig,
+) -> Result<(), DownloadError> {
+    let dir = puzzle::default_puzzles_dir(root_dir);
+    let filepath = puzzle::markdown_path(&dir, day);
+
+    tokio::fs::create_dir_all(&dir)
+        .await
+        .map_err(io_error(&dir))?;
+    tokio::fs::write(&filepath, puzzle::to_markdown(html, &config.base_url))
+        .await
+        .map_err(io_error(&filepath))?;
+
+    eprintln!(
+        "Saved the puzzle for day {day:02} to: {}",
+        filepath.to_string_lossy()
+    );
+    Ok(())
+}
+
+pub fn save_examples(day: u32, html: &str, root_dir: &Path) -> Result<(), DownloadError> {
+    let dir = examples::default_examples_dir(root_dir);
+    let extracted = puzzle::extract_examples(html);
+    let written = examples::save(&dir, day, &extracted, false)?;
+    eprintln!(
+        "Extracted {} example(s) for day {day:02} to: {}",
+        written.len(),
+        dir.to_string_lossy()
+    );
+    Ok(())
+}
+
+/// What to fetch and how hard to try.
+#[derive(Debug, Clone, Copy)]
+pub struct Settings {
+    pub examples: bool,
+    pub force: bool,
+    pub retries: u32,
+}
+
+pub async fn extract_input(
+    date: u32,
+    client: &reqwest::Client,
+    root_dir: &Path,
+    config: &ClientConfig,
+    settings: Settings,
+) -> Outcome {
+    let needs_input = settings.force || !input::input_path(root_dir, date).exists();
+    // Fixtures may have been edited by hand, so they're never fetched again once they're there.
+    let needs_examples = settings.examples
+        && !examples::default_examples_dir(root_dir)
+            .join(format!("{:02}-1.in", date))
+            .exists();
+    // Until the first part is solved, the page only has that part, so keep checking for the second.
+    let needs_puzzle = settings.force
+        || std::fs::read_to_string(puzzle::markdown_path(
+            &puzzle::default_puzzles_dir(root_dir),
+            date,
+        ))
+        .map_or(true, |markdown| !puzzle::has_both_parts(&markdown));
+
+    if !needs_input && !needs_examples && !needs_puzzle {
+        return Outcome::Skipped;
+    }
+
+    let result = async {
+        if needs_input {
+            let contents = with_retries(date, settings.retries, || {
+                download_inputs(date, client, config)
+            })
+            .await?;
+            save_inputs(date, contents, root_dir).await?;
+        }
+        if needs_examples || needs_puzzle {
+            let html = with_retries(date, settings.retries, || {
+                download_page(date, client, config)
+            })
+            .await?;
+            if needs_puzzle {
+                save_puzzle(date, &html, root_dir, config).await?;
+            }
+            if needs_examples {
+                save_examples(date, &html, root_dir)?;
+            }
+        }
+        Ok::<_, DownloadError>(())
+    }
+    .await;
+
+    match result {
+        Ok(()) => Outcome::Downloaded,
+        Err(err) => Outcome::Failed(err),
+    }
+}
+
+/// Download the inputs for the given days, at most `jobs` of them at a time,
+/// and report what happened to each of them in order.
+pub async fn extract_inputs(
     day_range: std::ops::Range<u32>,
     client: &reqwest::Client,
-    root_dir: P,
-    session: String,
-) where
-    P: AsRef<std::path::Path> + Clone + Send + Sync + 'static,
-{
+    root_dir: PathBuf,
+    config: ClientConfig,
+    settings: Settings,
+    jobs: usize,
+) -> Vec<(u32, Outcome)> {
+    let permits = Arc::new(Semaphore::new(jobs.max(1)));
     let mut handles = vec![];
     for date in day_range {
         let client = client.clone();
         let root_dir = root_dir.clone();
-        let session = session.clone();
+        let config = config.clone();
+        let permits = permits.clone();
 
-        handles.push(tokio::task::spawn(async move {
-            if let Err(err) = extract_input(date, client, root_dir, session.clone()).await {
-                eprintln!("{}", err);
-            }
-        }));
+        handles.push((
+            date,
+            tokio::task::spawn(async move {
+                let _permit = permits.acquire_owned().await.unwrap();
+                extract_input(date, &client, &root_dir, &config, settings).await
+            }),
+        ));
     }
 
-    for handle in handles {
-        _ = handle.await;
+    let mut outcomes = vec![];
+    for (date, handle) in handles {
+        let outcome = handle
+            .await
+            .unwrap_or_else(|err| Outcome::Failed(err.into()));
+        outcomes.push((date, outcome));
     }
+    outcomes
+}
+
+pub fn print_summary(outcomes: &[(u32, Outcome)]) {
+    println!("{:<5}{:<12}detail", "day", "status");
+    for (day, outcome) in outcomes {
+        let (status, detail) = match outcome {
+            Outcome::Downloaded => ("downloaded", String::new()),
+            Outcome::Skipped => ("skipped", "already on disk".to_string()),
+            Outcome::Failed(err) => ("FAILED", err.to_string()),
+        };
+        println!("{:<5}{:<12}{}", format!("{:02}", day), status, detail);
+    }
+
+    let count = |matches: fn(&Outcome) -> bool| {
+        outcomes
+            .iter()
+            .filter(|(_, outcome)| matches(outcome))
+            .count()
+    };
+    println!(
+        "{} downloaded, {} skipped, {} failed",
+        count(|outcome| matches!(outcome, Outcome::Downloaded)),
+        count(|outcome| matches!(outcome, Outcome::Skipped)),
+        count(|outcome| matches!(outcome, Outcome::Failed(_))),
+    );
 }
 
 #[tokio::main]
 pub async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    dotenv::dotenv().unwrap();
+    // A `.env` file is optional, the settings can come from the environment or the flags as well.
+    dotenv::dotenv().ok();
     let opts = Opts::parse();
 
+    let year = opts.client.year;
+    let now = Utc::now();
     let date_range = match opts.day {
-        Some(day) => day as u32..day as u32 + 1,
-        None => 1..(Utc::now().day() + 1).min(26),
-    };
-
-    let client = reqwest::Client::new();
-    let root_dir = match opts.data_dir {
-        Some(dir) => dir,
-        None => {
-            let path = std::path::Path::new(
-                &std::env::var("CARGO_MANIFEST_DIR").unwrap_or(".".to_string()),
+        Some(day) if !(1..=unlock::last_day(year)).contains(&day) => {
+            return Err(format!(
+                "There's no day {day}, the days go from 1 to {}.",
+                unlock::last_day(year)
+            )
+            .into());
+        }
+        Some(day) if unlock::is_released(year, day, now) => day..day + 1,
+        Some(day) if opts.wait => day..day,
+        Some(day) => {
+            return Err(format!(
+                "Day {day} of {year} doesn't unlock until {}; pass --wait to wait for it.",
+                unlock::unlocks_at(year, day)
             )
-            .join("data/");
-            path
+            .into());
         }
+        None => unlock::released_days(year, now),
     };
-    let aoc_session = match opts.aoc_session {
-        None => var("AOC_SESSION").expect("No AOC_SESSION provided."),
-        Some(session) => session,
+    let waiting_for = match (opts.wait, opts.day) {
+        (false, _) => None,
+        (true, Some(day)) if !unlock::is_released(year, day, now) => {
+            Some((day, unlock::unlocks_at(year, day)))
+        }
+        // Already out, so it's downloaded along with the rest.
+        (true, Some(_)) => None,
+        (true, None) => unlock::next_unlock(year, now),
     };
 
-    extract_inputs(date_range, &client, root_dir, aoc_session).await;
+    let client = reqwest::Client::builder()
+        .user_agent(&opts.client.user_agent)
+        .build()?;
+    let root_dir = opts.data_dir.unwrap_or_else(input::default_data_dir);
+    if opts.client.session.is_none() {
+        return Err("No AOC_SESSION provided.".into());
+    }
+
+    let settings = Settings {
+        examples: opts.examples,
+        force: opts.force,
+        retries: opts.retries,
+    };
+    let mut outcomes = extract_inputs(
+        date_range,
+        &client,
+        root_dir.clone(),
+        opts.client.clone(),
+        settings,
+        opts.jobs,
+    )
+    .await;
 
+    if opts.wait {
+        match waiting_for {
+            Some((day, at)) => {
+                if let Ok(remaining) = (at - Utc::now()).to_std() {
+                    eprintln!(
+                        "Waiting {}h {:02}m {:02}s for day {day:02} to unlock at {at}...",
+                        remaining.as_secs() / 3600,
+                        remaining.as_secs() / 60 % 60,
+                        remaining.as_secs() % 60
+                    );
+                    // The server's clock may be a little behind ours.
+                    tokio::time::sleep(remaining + UNLOCK_GRACE).await;
+                }
+                let outcome = extract_input(day, &client, &root_dir, &opts.client, settings).await;
+                outcomes.push((day, outcome));
+            }
+            None if opts.day.is_none() => {
+                eprintln!("Every puzzle of {year} is already out, there's nothing to wait for.")
+            }
+            None => {}
+        }
+    }
+    print_summary(&outcomes);
+
+    if outcomes
+        .iter()
+        .any(|(_, outcome)| matches!(outcome, Outcome::Failed(_)))
+    {
+        return Err("some inputs couldn't be downloaded.".into());
+    }
     Ok(())
 }
@@ -0,0 +1,103 @@
+//! Settings for talking to the Advent of Code servers,
+//! shared by the input downloader, the answer submitter and the leaderboard.
+use clap::Args;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("CARGO_PKG_REPOSITORY"),
+    ")"
+);
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct ClientConfig {
+    /// The year of the event to talk about.
+    #[arg(long, env = "AOC_YEAR", default_value_t = 2023)]
+    pub year: u32,
+    /// Where the Advent of Code server lives, e.g. a local stand-in for tests.
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    pub base_url: String,
+    /// The session cookie of a logged in user.
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    pub session: Option<String>,
+    #[arg(long, env = "AOC_USER_AGENT", default_value = DEFAULT_USER_AGENT)]
+    pub user_agent: String,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            year: 2023,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+        }
+    }
+}
+
+impl ClientConfig {
+    /// The page with the puzzle description.
+    pub fn day_url(&self, day: u32) -> String {
+        format!(
+            "{}/{}/day/{day}",
+            self.base_url.trim_end_matches('/'),
+            self.year
+        )
+    }
+
+    pub fn input_url(&self, day: u32) -> String {
+        format!("{}/input", self.day_url(day))
+    }
+
+    pub fn answer_url(&self, day: u32) -> String {
+        format!("{}/answer", self.day_url(day))
+    }
+
+    /// The JSON of a private leaderboard, by the id in its URL.
+    pub fn leaderboard_url(&self, id: u64) -> String {
+        format!(
+            "{}/{}/leaderboard/private/view/{id}.json",
+            self.base_url.trim_end_matches('/'),
+            self.year
+        )
+    }
+
+    /// The `Cookie` header that authenticates us, if there's a session.
+    pub fn cookie(&self) -> Result<String, String> {
+        match &self.session {
+            Some(session) => Ok(format!("session={session}")),
+            None => Err("no session provided; pass --session or set AOC_SESSION.".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn urls() {
+        let config = ClientConfig {
+            year: 2022,
+            base_url: "http://localhost:8080/".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(config.day_url(5), "http://localhost:8080/2022/day/5");
+        assert_eq!(
+            config.input_url(5),
+            "http://localhost:8080/2022/day/5/input"
+        );
+        assert_eq!(
+            ClientConfig::default().answer_url(25),
+            "https://adventofcode.com/2023/day/25/answer"
+        );
+        assert_eq!(
+            config.leaderboard_url(1234),
+            "http://localhost:8080/2022/leaderboard/private/view/1234.json"
+        );
+        assert!(config.cookie().is_err());
+    }
+}
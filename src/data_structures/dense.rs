@@ -0,0 +1,289 @@
+use crate::diagnostics::Location;
+use crate::error::ParseError;
+
+use super::{Direction, Grid2DParseError, NeighborhoodShape, Point};
+use std::str::FromStr;
+
+/// A rectangular grid that has a value in every cell, stored row after row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid2D<T> {
+    cells: Vec<T>,
+    pub rows: usize,
+    pub columns: usize,
+}
+
+/// Every character has to be a cell and every row as wide as the first one,
+/// so the errors are those of [`super::ParseMode::Strict`].
+impl<T> FromStr for Grid2D<T>
+where
+    T: TryFrom<char>,
+{
+    type Err = Grid2DParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cells = vec![];
+        let mut columns = None;
+        let mut rows = 0;
+
+        for (row_idx, row) in s.lines().enumerate() {
+            let width = row.chars().count();
+            let expected = *columns.get_or_insert(width);
+            if width != expected {
+                return Err(Grid2DParseError::ragged(expected, row_idx, row));
+            }
+
+            for (col_idx, val) in row.chars().enumerate() {
+                let cell = T::try_from(val).map_err(|_| Grid2DParseError::UnknownCell {
+                    value: val,
+                    location: Location::new(row_idx + 1, col_idx + 1, 1, row),
+                })?;
+                cells.push(cell);
+            }
+            rows += 1;
+        }
+
+        Ok(Self {
+            cells,
+            rows,
+            columns: columns.ok_or(Grid2DParseError::Empty)?,
+        })
+    }
+}
+
+impl<T> std::fmt::Display for Grid2D<T>
+where
+    T: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (row_idx, row) in self.iter_rows().enumerate() {
+            if row_idx > 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                write!(f, "{value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> std::ops::Index<Point> for Grid2D<T> {
+    type Output = T;
+    fn index(&self, (row, column): Point) -> &Self::Output {
+        assert!(
+            row < self.rows && column < self.columns,
+            "({row}, {column}) is outside of the {}x{} grid",
+            self.rows,
+            self.columns
+        );
+        &self.cells[row * self.columns + column]
+    }
+}
+
+impl<T> std::ops::IndexMut<Point> for Grid2D<T> {
+    fn index_mut(&mut self, (row, column): Point) -> &mut Self::Output {
+        assert!(
+            row < self.rows && column < self.columns,
+            "({row}, {column}) is outside of the {}x{} grid",
+            self.rows,
+            self.columns
+        );
+        &mut self.cells[row * self.columns + column]
+    }
+}
+
+impl<T> Grid2D<T> {
+    /// A grid with the same value in every cell.
+    pub fn new(rows: usize, columns: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; rows * columns],
+            rows,
+            columns,
+        }
+    }
+
+    /// Build a grid out of its rows, which all have to be equally wide.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, Grid2DParseError> {
+        let num_rows = rows.len();
+        let columns = rows.first().map(Vec::len).ok_or(Grid2DParseError::Empty)?;
+        if let Some((row_idx, row)) = rows
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.len() != columns)
+        {
+            return Err(ParseError::new(
+                format!("a row of {columns} cells"),
+                format!("row {} of {} cells", row_idx + 1, row.len()),
+            )
+            .into());
+        }
+
+        Ok(Self {
+            cells: rows.into_iter().flatten().collect(),
+            rows: num_rows,
+            columns,
+        })
+    }
+
+    /// The cells have to be laid out row after row.
+    pub(super) fn from_cells(rows: usize, columns: usize, cells: Vec<T>) -> Self {
+        debug_assert_eq!(cells.len(), rows * columns);
+        Self {
+            cells,
+            rows,
+            columns,
+        }
+    }
+
+    fn index_of(&self, coordinate: (isize, isize)) -> Option<usize> {
+        if coordinate.0 < 0 || coordinate.0 >= self.rows as isize {
+            return None;
+        }
+        if coordinate.1 < 0 || coordinate.1 >= self.columns as isize {
+            return None;
+        }
+        Some(coordinate.0 as usize * self.columns + coordinate.1 as usize)
+    }
+
+    pub fn at(&self, coordinate: (isize, isize)) -> Option<&T> {
+        self.index_of(coordinate).map(|index| &self.cells[index])
+    }
+
+    pub fn at_mut(&mut self, coordinate: (isize, isize)) -> Option<&mut T> {
+        self.index_of(coordinate)
+            .map(|index| &mut self.cells[index])
+    }
+
+    /// The cells next to the given one that are on the grid, along with the direction they're in.
+    pub fn neighbors(
+        &self,
+        coordinate: Point,
+        shape: NeighborhoodShape,
+    ) -> impl Iterator<Item = (Point, Direction, &T)> {
+        Direction::all_from_shape(shape)
+            .into_iter()
+            .filter_map(move |direction| {
+                let (delta_row, delta_column) = direction.into();
+                let target_coordinate = (
+                    coordinate.0 as isize + delta_row,
+                    coordinate.1 as isize + delta_column,
+                );
+                let value = self.at(target_coordinate)?;
+                Some((
+                    (target_coordinate.0 as usize, target_coordinate.1 as usize),
+                    direction,
+                    value,
+                ))
+            })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.columns..(row + 1) * self.columns]
+    }
+
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        (0..self.rows).map(move |row| &self[(row, column)])
+    }
+
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        (0..self.rows).map(|row| self.row(row))
+    }
+
+    pub fn iter_columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+        (0..self.columns).map(|column| self.column(column))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, value)| ((index / self.columns, index % self.columns), value))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> + '_ {
+        let columns = self.columns;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(index, value)| ((index / columns, index % columns), value))
+    }
+
+    /// Where the given value is on the grid, row by row.
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(point, _)| point)
+    }
+
+    pub fn position_of(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.positions_of(value).next()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid2D<U> {
+        Grid2D {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_search() {
+        let mut grid = "#.S\n..#".parse::<Grid2D<char>>().unwrap();
+        assert_eq!((grid.rows, grid.columns), (2, 3));
+        assert_eq!(grid.to_string(), "#.S\n..#");
+        assert_eq!(grid.position_of(&'S'), Some((0, 2)));
+        assert_eq!(
+            grid.positions_of(&'#').collect::<Vec<_>>(),
+            vec![(0, 0), (1, 2)]
+        );
+        assert_eq!(grid.at((1, 3)), None);
+        assert_eq!(grid.at((-1, 0)), None);
+
+        *grid.at_mut((1, 0)).unwrap() = 'O';
+        grid[(1, 1)] = 'O';
+        assert_eq!(grid.row(1), &['O', 'O', '#']);
+        assert_eq!(grid.column(2).collect::<String>(), "S#",);
+        assert_eq!(grid.iter_columns().count(), 3);
+
+        assert!(matches!(
+            "ab\nabc".parse::<Grid2D<char>>(),
+            Err(Grid2DParseError::Ragged {
+                expected: 2,
+                found: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            "".parse::<Grid2D<char>>(),
+            Err(Grid2DParseError::Empty)
+        ));
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid2D::new(2, 2, 0);
+        let mut neighbors: Vec<_> = grid
+            .neighbors((0, 0), NeighborhoodShape::Box)
+            .map(|(point, _, _)| point)
+            .collect();
+        neighbors.sort();
+        assert_eq!(neighbors, vec![(0, 1), (1, 0), (1, 1)]);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::diagnostics::Location;
+use crate::error::ParseError;
 use std::{collections::HashMap, str::FromStr};
 use thiserror::Error;
 
@@ -5,10 +7,52 @@ pub type Point = (usize, usize);
 
 #[derive(Debug, Clone, Error)]
 pub enum Grid2DParseError {
-    #[error("Failed to parse into a 2d grid")]
-    BadData,
+    #[error("the grid is empty")]
+    Empty,
+    #[error("row {} is {found} wide instead of {expected}\n{}", location.line, location.snippet())]
+    Ragged {
+        expected: usize,
+        found: usize,
+        location: Location,
+    },
+    #[error("unknown cell {value:?} at {location}\n{}", location.snippet())]
+    UnknownCell { value: char, location: Location },
+    #[error(transparent)]
+    BadData(#[from] ParseError),
 }
 
+impl Grid2DParseError {
+    /// The row at `row_idx` (from 0) isn't `expected` characters wide. Underline the extra
+    /// characters, or the spots where the missing ones should be.
+    pub(super) fn ragged(expected: usize, row_idx: usize, row: &str) -> Self {
+        let found = row.chars().count();
+        Grid2DParseError::Ragged {
+            expected,
+            found,
+            location: Location::new(
+                row_idx + 1,
+                expected.min(found) + 1,
+                expected.abs_diff(found),
+                row,
+            ),
+        }
+    }
+}
+
+/// How forgiving parsing a grid is about the shape of the input and the characters in it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Every row has to be as wide as the first one, and every character has to be
+    /// either a cell or a [`BLANK`].
+    #[default]
+    Strict,
+    /// Rows keep their own widths, and characters that aren't cells are left out.
+    Lenient,
+}
+
+/// The character that stands for an empty spot in the puzzles.
+pub const BLANK: char = '.';
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Direction {
     Up,
@@ -55,6 +99,61 @@ impl Direction {
             Direction::BottomRight => Some(Direction::TopLeft),
         }
     }
+
+    fn from_delta(delta: (isize, isize)) -> Option<Direction> {
+        match delta {
+            (-1, 0) => Some(Direction::Up),
+            (0, -1) => Some(Direction::Left),
+            (0, 1) => Some(Direction::Right),
+            (1, 0) => Some(Direction::Down),
+            (-1, -1) => Some(Direction::TopLeft),
+            (-1, 1) => Some(Direction::TopRight),
+            (1, -1) => Some(Direction::BottomLeft),
+            (1, 1) => Some(Direction::BottomRight),
+            _ => None,
+        }
+    }
+
+    /// A quarter turn counterclockwise, e.g. from `Up` to `Left`.
+    pub fn turn_left(self) -> Direction {
+        let (row, column) = self.into();
+        Direction::from_delta((-column, row)).unwrap()
+    }
+
+    /// A quarter turn clockwise, e.g. from `Up` to `Right`.
+    pub fn turn_right(self) -> Direction {
+        let (row, column) = self.into();
+        Direction::from_delta((column, -row)).unwrap()
+    }
+
+    /// Where something heading this way goes after bouncing off a `/` or `\` mirror,
+    /// e.g. `Right` turns `Up` at a `/`. Other characters aren't mirrors.
+    pub fn reflect(self, mirror: char) -> Option<Direction> {
+        let (row, column) = self.into();
+        match mirror {
+            '/' => Direction::from_delta((-column, -row)),
+            '\\' => Direction::from_delta((column, row)),
+            _ => None,
+        }
+    }
+}
+
+/// The ways the puzzles write down directions: `U`/`D`/`L`/`R`, compass points `N`/`S`/`E`/`W`
+/// with north up, and arrows `^`/`v`/`<`/`>`.
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'U' | 'N' | '^' => Ok(Direction::Up),
+            'D' | 'S' | 'v' => Ok(Direction::Down),
+            'L' | 'W' | '<' => Ok(Direction::Left),
+            'R' | 'E' | '>' => Ok(Direction::Right),
+            _ => Err(ParseError::new(
+                "a direction like `U`, `N` or `^`",
+                value.to_string(),
+            )),
+        }
+    }
 }
 
 impl From<Direction> for (isize, isize) {
@@ -65,8 +164,8 @@ impl From<Direction> for (isize, isize) {
             Direction::Right => (0, 1),
             Direction::Down => (1, 0),
             Direction::TopLeft => (-1, -1),
-            Direction::TopRight => (1, -1),
-            Direction::BottomLeft => (-1, 1),
+            Direction::TopRight => (-1, 1),
+            Direction::BottomLeft => (1, -1),
             Direction::BottomRight => (1, 1),
         }
     }
@@ -76,7 +175,9 @@ impl From<Direction> for (isize, isize) {
 pub struct SparseGrid2D<T> {
     inner: HashMap<Point, T>,
     pub rows: usize,
+    /// The width of the widest row.
     pub columns: usize,
+    widths: Vec<usize>,
 }
 
 #[derive(Debug)]
@@ -129,44 +230,72 @@ where
 {
     type Err = Grid2DParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut num_rows = 0;
-        let mut num_cols = None;
+        Self::parse(s, ParseMode::default())
+    }
+}
+
+impl<T> SparseGrid2D<T>
+where
+    Item<T>: TryFrom<char>,
+{
+    pub fn parse(s: &str, mode: ParseMode) -> Result<Self, Grid2DParseError> {
         let mut inner = std::collections::HashMap::<Point, T>::new();
+        let mut widths = vec![];
 
-        s.lines().enumerate().for_each(|(row_idx, row)| {
-            num_rows += 1;
-            let mut current_columns = 0;
-            row.chars().enumerate().for_each(|(col_idx, val)| {
-                current_columns += 1;
+        for (row_idx, row) in s.lines().enumerate() {
+            let width = row.chars().count();
+            if let (ParseMode::Strict, Some(&expected)) = (mode, widths.first()) {
+                if width != expected {
+                    return Err(Grid2DParseError::ragged(expected, row_idx, row));
+                }
+            }
 
+            for (col_idx, val) in row.chars().enumerate() {
                 match val.try_into() {
                     Ok(Item::Keep(value)) => {
                         inner.insert((row_idx, col_idx), value);
                     }
-                    Ok(Item::Skip) => {}
-                    _ => {}
+                    Ok(Item::Skip) if mode == ParseMode::Lenient || val == BLANK => {}
+                    Ok(Item::Skip) => {
+                        return Err(Grid2DParseError::UnknownCell {
+                            value: val,
+                            location: Location::new(row_idx + 1, col_idx + 1, 1, row),
+                        });
+                    }
+                    Err(_) => {
+                        return Err(ParseError::new("a grid cell", val.to_string())
+                            .at(Location::new(row_idx + 1, col_idx + 1, 1, row))
+                            .into());
+                    }
                 }
-            });
-
-            if num_cols.is_none() {
-                num_cols = Some(current_columns);
             }
-        });
+            widths.push(width);
+        }
 
         Ok(Self {
             inner,
-            rows: num_rows,
-            columns: num_cols.unwrap(),
+            rows: widths.len(),
+            columns: widths
+                .iter()
+                .copied()
+                .max()
+                .ok_or(Grid2DParseError::Empty)?,
+            widths,
         })
     }
 }
 
 impl<T> SparseGrid2D<T> {
+    /// How many characters wide the row was in the input.
+    pub fn row_width(&self, row: usize) -> Option<usize> {
+        self.widths.get(row).copied()
+    }
+
     pub fn at(&self, coordinate: (isize, isize)) -> Option<&T> {
         if coordinate.0 < 0 || coordinate.0 >= self.rows as isize {
             return None;
         }
-        if coordinate.1 < 0 || coordinate.1 >= self.columns as isize {
+        if coordinate.1 < 0 || coordinate.1 >= self.widths[coordinate.0 as usize] as isize {
             return None;
         }
         self.inner
@@ -191,7 +320,9 @@ impl<T> SparseGrid2D<T> {
                 if target_coordinate.0 < 0 || target_coordinate.0 >= self.rows as isize {
                     return None;
                 }
-                if target_coordinate.1 < 0 || target_coordinate.1 >= self.columns as isize {
+                if target_coordinate.1 < 0
+                    || target_coordinate.1 >= self.widths[target_coordinate.0 as usize] as isize
+                {
                     return None;
                 }
                 Some((
@@ -203,3 +334,126 @@ impl<T> SparseGrid2D<T> {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions() {
+        let delta = |direction: Direction| -> (isize, isize) { direction.into() };
+        let box_directions = Direction::all_from_shape(NeighborhoodShape::Box);
+
+        // The diagonals are made of the straight steps their names are made of.
+        let sum = |a: Direction, b: Direction| (delta(a).0 + delta(b).0, delta(a).1 + delta(b).1);
+        assert_eq!(
+            delta(Direction::TopLeft),
+            sum(Direction::Up, Direction::Left)
+        );
+        assert_eq!(
+            delta(Direction::TopRight),
+            sum(Direction::Up, Direction::Right)
+        );
+        assert_eq!(
+            delta(Direction::BottomLeft),
+            sum(Direction::Down, Direction::Left)
+        );
+        assert_eq!(
+            delta(Direction::BottomRight),
+            sum(Direction::Down, Direction::Right)
+        );
+
+        for &direction in &box_directions {
+            let opposite = direction.opposite().unwrap();
+            assert_eq!(delta(opposite), (-delta(direction).0, -delta(direction).1));
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), opposite);
+            assert_eq!(
+                direction.reflect('/').unwrap().reflect('/'),
+                Some(direction)
+            );
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::TopRight.turn_right(), Direction::BottomRight);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+
+        assert_eq!(Direction::Right.reflect('/'), Some(Direction::Up));
+        assert_eq!(Direction::Down.reflect('/'), Some(Direction::Left));
+        assert_eq!(Direction::Right.reflect('\\'), Some(Direction::Down));
+        assert_eq!(Direction::Up.reflect('\\'), Some(Direction::Left));
+        assert_eq!(Direction::Up.reflect('|'), None);
+
+        for (chars, direction) in [
+            ("UN^", Direction::Up),
+            ("DSv", Direction::Down),
+            ("LW<", Direction::Left),
+            ("RE>", Direction::Right),
+        ] {
+            for c in chars.chars() {
+                assert_eq!(Direction::try_from(c), Ok(direction));
+            }
+        }
+        assert!(Direction::try_from('x').is_err());
+    }
+
+    #[test]
+    fn parse() {
+        let grid = "ab\ncd".parse::<SparseGrid2D<u32>>().unwrap();
+        assert_eq!((grid.rows, grid.columns), (2, 2));
+        assert_eq!(grid.at((1, 0)), Some(&('c' as u32)));
+
+        assert!(matches!(
+            "".parse::<SparseGrid2D<u32>>(),
+            Err(Grid2DParseError::Empty)
+        ));
+        assert!(matches!(
+            SparseGrid2D::<u32>::parse("", ParseMode::Strict),
+            Err(Grid2DParseError::Empty)
+        ));
+        // Ragged rows need the lenient mode to be asked for.
+        assert!(matches!(
+            "12\n1".parse::<SparseGrid2D<u8>>(),
+            Err(Grid2DParseError::Ragged { .. })
+        ));
+    }
+
+    #[test]
+    fn strict() {
+        let err = SparseGrid2D::<u8>::parse("123\n12345\n123", ParseMode::Strict).unwrap_err();
+        assert!(matches!(
+            err,
+            Grid2DParseError::Ragged {
+                expected: 3,
+                found: 5,
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "row 2 is 5 wide instead of 3\n  |\n2 | 12345\n  |    ^^"
+        );
+
+        // `u8` can't hold every character, so those are unknown rather than blank.
+        let err = SparseGrid2D::<u8>::parse("1.3\n4λ6", ParseMode::Strict).unwrap_err();
+        let Grid2DParseError::UnknownCell { value, location } = err else {
+            panic!("expected an unknown cell, got {err:?}");
+        };
+        assert_eq!((value, location.line, location.column), ('λ', 2, 2));
+    }
+
+    #[test]
+    fn lenient() {
+        let grid = SparseGrid2D::<u8>::parse("123\n12345\n1", ParseMode::Lenient).unwrap();
+        assert_eq!((grid.rows, grid.columns), (3, 5));
+        assert_eq!(grid.row_width(2), Some(1));
+        assert_eq!(grid.at((1, 4)), Some(&b'5'));
+        // Past the end of its own row, even though other rows are wider.
+        assert_eq!(grid.at((2, 1)), None);
+        assert_eq!(
+            grid.neighbors((2, 0), NeighborhoodShape::Plus)
+                .map(|(point, _, _)| point)
+                .collect::<Vec<_>>(),
+            vec![(1, 0)]
+        );
+    }
+}
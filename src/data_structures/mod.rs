@@ -1,11 +1,19 @@
 mod grid;
 pub use grid::*;
 
+mod dense;
+pub use dense::*;
+
+mod view;
+pub use view::*;
+
+mod tiling;
+pub use tiling::*;
+
 mod polygon;
 pub use polygon::*;
 
 mod heap;
 pub use heap::*;
 
-mod graph;
-pub use graph::*;
\ No newline at end of file
+mod graph;
\ No newline at end of file
@@ -9,10 +9,14 @@ pub struct LateralPolygon {
 }
 
 impl LateralPolygon {
+    /// The last vertex may repeat the first one to close the loop, as when tracing a path
+    /// back to where it started. It's dropped so that no vertex is counted twice.
     pub fn new(coordinates: impl Iterator<Item = Coord>) -> Self {
-        Self {
-            coordinates: coordinates.collect(),
+        let mut coordinates: Vec<_> = coordinates.collect();
+        if coordinates.len() > 1 && coordinates.first() == coordinates.last() {
+            coordinates.pop();
         }
+        Self { coordinates }
     }
 
     pub fn bounding_box(&self) -> (Coord, Coord) {
@@ -26,31 +30,40 @@ impl LateralPolygon {
     /// Return an iterator over the integer points that lie on the edges
     /// of the polygon but not at the vertices.
     pub fn edges(&self) -> impl Iterator<Item = Coord> + '_ {
-        self.coordinates.as_slice().windows(2).flat_map(|window| {
-            let (prev_x, prev_y) = window[0];
-            let (curr_x, curr_y) = window[1];
-            let range = {
-                // Vertical line.
-                if prev_x == curr_x {
-                    // Don't include endpoints at all.
-                    prev_y.min(curr_y) + 1..prev_y.max(curr_y)
-                }
-                // Horizontal line.
-                else if prev_y == curr_y {
-                    // Don't include endpoints at all.
-                    prev_x.min(curr_x) + 1..prev_x.max(curr_x)
-                } else {
-                    panic!("not a standing/sleeping line");
-                }
-            };
-            range.map(move |c| {
-                if prev_x == curr_x {
-                    (prev_x, c)
-                } else {
-                    (c, prev_y)
-                }
+        let closing_vertex = self.coordinates.first().copied();
+        self.coordinates
+            .iter()
+            .copied()
+            .zip(
+                self.coordinates
+                    .iter()
+                    .copied()
+                    .skip(1)
+                    .chain(closing_vertex),
+            )
+            .flat_map(|((prev_x, prev_y), (curr_x, curr_y))| {
+                let range = {
+                    // Vertical line.
+                    if prev_x == curr_x {
+                        // Don't include endpoints at all.
+                        prev_y.min(curr_y) + 1..prev_y.max(curr_y)
+                    }
+                    // Horizontal line.
+                    else if prev_y == curr_y {
+                        // Don't include endpoints at all.
+                        prev_x.min(curr_x) + 1..prev_x.max(curr_x)
+                    } else {
+                        panic!("not a standing/sleeping line");
+                    }
+                };
+                range.map(move |c| {
+                    if prev_x == curr_x {
+                        (prev_x, c)
+                    } else {
+                        (c, prev_y)
+                    }
+                })
             })
-        })
     }
 
     /// Compute the area of the polygon using the [Shoelace formula].
@@ -88,6 +101,16 @@ impl LateralPolygon {
     pub fn perimeter(&self) -> usize {
         self.boundary().count()
     }
+
+    /// Count the integer points inside the polygon or on its boundary.
+    ///
+    /// [Pick's Theorem] relates the area to the points inside (`i`) and on the boundary (`b`):
+    /// `A = i + b/2 - 1`, so `i + b = A + b/2 + 1`.
+    ///
+    /// [Pick's Theorem]: https://en.wikipedia.org/wiki/Pick%27s_theorem
+    pub fn lattice_points(&self) -> usize {
+        self.shoelace_area() + self.perimeter() / 2 + 1
+    }
 }
 
 #[cfg(test)]
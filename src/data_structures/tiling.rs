@@ -0,0 +1,90 @@
+use super::{Direction, Grid2D, NeighborhoodShape, Point};
+
+/// A [`Grid2D`] repeated infinitely in every direction, like wallpaper.
+/// Any coordinate is on it, so there's no edge to check for when walking it.
+#[derive(Debug)]
+pub struct Tiling<'a, T> {
+    grid: &'a Grid2D<T>,
+}
+
+// Not derived, for the same reason as `GridView`'s.
+impl<T> Clone for Tiling<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Tiling<'_, T> {}
+
+impl<T> Grid2D<T> {
+    /// The grid as the tile at `(0, 0)` of an infinite tiling, which has to have at least a cell.
+    pub fn tiled(&self) -> Tiling<'_, T> {
+        assert!(
+            self.rows > 0 && self.columns > 0,
+            "an empty grid can't be tiled"
+        );
+        Tiling { grid: self }
+    }
+}
+
+impl<'a, T> Tiling<'a, T> {
+    /// Where a coordinate lands on the grid, along with the (row, column) of the tile it's in.
+    /// The grid itself is the tile at `(0, 0)`, and the one to its top left is at `(-1, -1)`.
+    pub fn locate(&self, (row, column): (isize, isize)) -> (Point, (isize, isize)) {
+        let (rows, columns) = (self.grid.rows as isize, self.grid.columns as isize);
+        (
+            (
+                row.rem_euclid(rows) as usize,
+                column.rem_euclid(columns) as usize,
+            ),
+            (row.div_euclid(rows), column.div_euclid(columns)),
+        )
+    }
+
+    pub fn at(&self, coordinate: (isize, isize)) -> &'a T {
+        let grid = self.grid;
+        &grid[self.locate(coordinate).0]
+    }
+
+    /// The cells next to the given one, along with the direction they're in.
+    pub fn neighbors(
+        &self,
+        coordinate: (isize, isize),
+        shape: NeighborhoodShape,
+    ) -> impl Iterator<Item = ((isize, isize), Direction, &'a T)> + 'a {
+        let tiling = *self;
+        Direction::all_from_shape(shape)
+            .into_iter()
+            .map(move |direction| {
+                let (delta_row, delta_column) = direction.into();
+                let target = (coordinate.0 + delta_row, coordinate.1 + delta_column);
+                (target, direction, tiling.at(target))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_around() {
+        let grid = "ab\ncd\nef".parse::<Grid2D<char>>().unwrap();
+        let tiling = grid.tiled();
+
+        assert_eq!(tiling.locate((1, 1)), ((1, 1), (0, 0)));
+        assert_eq!(tiling.locate((-1, 2)), ((2, 0), (-1, 1)));
+        assert_eq!(tiling.locate((-7, -5)), ((2, 1), (-3, -3)));
+        assert_eq!(*tiling.at((3, -1)), 'b');
+
+        let mut neighbors: Vec<_> = tiling
+            .neighbors((0, 0), NeighborhoodShape::Plus)
+            .map(|(coordinate, _, &value)| (coordinate, value))
+            .collect();
+        neighbors.sort();
+        assert_eq!(
+            neighbors,
+            vec![((-1, 0), 'e'), ((0, -1), 'b'), ((0, 1), 'b'), ((1, 0), 'c')]
+        );
+    }
+}
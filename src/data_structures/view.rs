@@ -0,0 +1,436 @@
+use super::{Grid2D, Point};
+
+/// Where the cells of a view are on the grid underneath: the cell at `(row, column)`
+/// of the view is the one at `base + row * row_step + column * column_step` on the grid.
+/// Transforming a view only changes these, so it never copies a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Frame {
+    base: (isize, isize),
+    row_step: (isize, isize),
+    column_step: (isize, isize),
+    rows: usize,
+    columns: usize,
+}
+
+impl Frame {
+    fn whole(rows: usize, columns: usize) -> Self {
+        Self {
+            base: (0, 0),
+            row_step: (1, 0),
+            column_step: (0, 1),
+            rows,
+            columns,
+        }
+    }
+
+    fn offset(&self, (row, column): (isize, isize)) -> (isize, isize) {
+        (
+            self.base.0 + row * self.row_step.0 + column * self.column_step.0,
+            self.base.1 + row * self.row_step.1 + column * self.column_step.1,
+        )
+    }
+
+    fn locate(&self, coordinate: (isize, isize)) -> Option<Point> {
+        if coordinate.0 < 0 || coordinate.0 >= self.rows as isize {
+            return None;
+        }
+        if coordinate.1 < 0 || coordinate.1 >= self.columns as isize {
+            return None;
+        }
+        let (row, column) = self.offset(coordinate);
+        Some((row as usize, column as usize))
+    }
+
+    fn point(&self, (row, column): Point) -> Point {
+        self.locate((row as isize, column as isize))
+            .unwrap_or_else(|| {
+                panic!(
+                    "({row}, {column}) is outside of the {}x{} view",
+                    self.rows, self.columns
+                )
+            })
+    }
+
+    fn transpose(self) -> Self {
+        Self {
+            row_step: self.column_step,
+            column_step: self.row_step,
+            rows: self.columns,
+            columns: self.rows,
+            ..self
+        }
+    }
+
+    fn flip_v(self) -> Self {
+        Self {
+            base: self.offset((self.rows.saturating_sub(1) as isize, 0)),
+            row_step: (-self.row_step.0, -self.row_step.1),
+            ..self
+        }
+    }
+
+    fn flip_h(self) -> Self {
+        Self {
+            base: self.offset((0, self.columns.saturating_sub(1) as isize)),
+            column_step: (-self.column_step.0, -self.column_step.1),
+            ..self
+        }
+    }
+
+    fn sub(self, origin: Point, rows: usize, columns: usize) -> Self {
+        assert!(
+            origin.0 + rows <= self.rows && origin.1 + columns <= self.columns,
+            "a {rows}x{columns} view at {origin:?} doesn't fit in the {}x{} view",
+            self.rows,
+            self.columns
+        );
+        Self {
+            base: self.offset((origin.0 as isize, origin.1 as isize)),
+            rows,
+            columns,
+            ..self
+        }
+    }
+}
+
+/// A rectangle of a [`Grid2D`] seen transposed, rotated or flipped, without copying it.
+/// Transforms compose, e.g. `grid.rotate_cw().flip_v()`, and all of them are cheap.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid2D<T>,
+    frame: Frame,
+}
+
+// Derived, these would only be there for the cells that are `Copy` themselves.
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+/// Like [`GridView`], but the cells can be changed through it.
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    grid: &'a mut Grid2D<T>,
+    frame: Frame,
+}
+
+impl<T> Grid2D<T> {
+    /// The whole grid as it is.
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            frame: Frame::whole(self.rows, self.columns),
+        }
+    }
+
+    pub fn view_mut(&mut self) -> GridViewMut<'_, T> {
+        let frame = Frame::whole(self.rows, self.columns);
+        GridViewMut { grid: self, frame }
+    }
+
+    /// Rows become columns and columns become rows.
+    pub fn transpose(&self) -> GridView<'_, T> {
+        self.view().transpose()
+    }
+
+    pub fn rotate_cw(&self) -> GridView<'_, T> {
+        self.view().rotate_cw()
+    }
+
+    pub fn rotate_ccw(&self) -> GridView<'_, T> {
+        self.view().rotate_ccw()
+    }
+
+    /// Mirrored left to right.
+    pub fn flip_h(&self) -> GridView<'_, T> {
+        self.view().flip_h()
+    }
+
+    /// Upside down.
+    pub fn flip_v(&self) -> GridView<'_, T> {
+        self.view().flip_v()
+    }
+
+    /// The `rows` by `columns` rectangle with its top left corner at `origin`.
+    pub fn sub(&self, origin: Point, rows: usize, columns: usize) -> GridView<'_, T> {
+        self.view().sub(origin, rows, columns)
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn rows(&self) -> usize {
+        self.frame.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.frame.columns
+    }
+
+    pub fn at(&self, coordinate: (isize, isize)) -> Option<&'a T> {
+        let grid = self.grid;
+        self.frame.locate(coordinate).map(|point| &grid[point])
+    }
+
+    pub fn transpose(self) -> Self {
+        Self {
+            frame: self.frame.transpose(),
+            ..self
+        }
+    }
+
+    pub fn rotate_cw(self) -> Self {
+        self.transpose().flip_h()
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        self.transpose().flip_v()
+    }
+
+    pub fn flip_h(self) -> Self {
+        Self {
+            frame: self.frame.flip_h(),
+            ..self
+        }
+    }
+
+    pub fn flip_v(self) -> Self {
+        Self {
+            frame: self.frame.flip_v(),
+            ..self
+        }
+    }
+
+    /// The `rows` by `columns` rectangle with its top left corner at `origin`, both as seen in this view.
+    pub fn sub(self, origin: Point, rows: usize, columns: usize) -> Self {
+        Self {
+            frame: self.frame.sub(origin, rows, columns),
+            ..self
+        }
+    }
+
+    pub fn row(&self, row: usize) -> impl DoubleEndedIterator<Item = &'a T> + 'a {
+        let (grid, frame) = (self.grid, self.frame);
+        (0..frame.columns).map(move |column| &grid[frame.point((row, column))])
+    }
+
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = &'a T> + 'a {
+        let (grid, frame) = (self.grid, self.frame);
+        (0..frame.rows).map(move |row| &grid[frame.point((row, column))])
+    }
+
+    pub fn iter_rows(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &'a T> + 'a> + 'a {
+        let view = *self;
+        (0..self.rows()).map(move |row| view.row(row))
+    }
+
+    pub fn iter_columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &'a T> + 'a> + 'a {
+        let view = *self;
+        (0..self.columns()).map(move |column| view.column(column))
+    }
+
+    /// Every cell along with its position in the view, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        let (grid, frame) = (self.grid, self.frame);
+        (0..frame.rows).flat_map(move |row| {
+            (0..frame.columns).map(move |column| ((row, column), &grid[frame.point((row, column))]))
+        })
+    }
+
+    /// Every `rows` by `columns` rectangle of the view along with its top left corner, row by row,
+    /// e.g. to search for a pattern.
+    pub fn windows(
+        &self,
+        rows: usize,
+        columns: usize,
+    ) -> impl Iterator<Item = (Point, GridView<'a, T>)> + 'a {
+        let view = *self;
+        let row_origins = 0..(view.rows() + 1).saturating_sub(rows);
+        row_origins.flat_map(move |row| {
+            (0..(view.columns() + 1).saturating_sub(columns))
+                .map(move |column| ((row, column), view.sub((row, column), rows, columns)))
+        })
+    }
+
+    /// Copy the cells out into a grid of their own, laid out as seen in the view.
+    pub fn to_grid(&self) -> Grid2D<T>
+    where
+        T: Clone,
+    {
+        let cells = self.iter().map(|(_, value)| value.clone()).collect();
+        Grid2D::from_cells(self.rows(), self.columns(), cells)
+    }
+}
+
+impl<T> std::ops::Index<Point> for GridView<'_, T> {
+    type Output = T;
+    fn index(&self, point: Point) -> &Self::Output {
+        &self.grid[self.frame.point(point)]
+    }
+}
+
+impl<T> PartialEq for GridView<'_, T>
+where
+    T: PartialEq,
+{
+    /// Views are equal when they show the same cells in the same layout,
+    /// no matter where they're taken from.
+    fn eq(&self, other: &Self) -> bool {
+        self.rows() == other.rows()
+            && self.columns() == other.columns()
+            && self
+                .iter()
+                .map(|(_, value)| value)
+                .eq(other.iter().map(|(_, value)| value))
+    }
+}
+
+impl<T> std::fmt::Display for GridView<'_, T>
+where
+    T: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (row_idx, row) in self.iter_rows().enumerate() {
+            if row_idx > 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                write!(f, "{value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> GridViewMut<'_, T> {
+    pub fn rows(&self) -> usize {
+        self.frame.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.frame.columns
+    }
+
+    pub fn at(&self, coordinate: (isize, isize)) -> Option<&T> {
+        self.frame.locate(coordinate).map(|point| &self.grid[point])
+    }
+
+    pub fn at_mut(&mut self, coordinate: (isize, isize)) -> Option<&mut T> {
+        self.frame
+            .locate(coordinate)
+            .map(|point| &mut self.grid[point])
+    }
+
+    pub fn transpose(self) -> Self {
+        Self {
+            frame: self.frame.transpose(),
+            ..self
+        }
+    }
+
+    pub fn rotate_cw(self) -> Self {
+        self.transpose().flip_h()
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        self.transpose().flip_v()
+    }
+
+    pub fn flip_h(self) -> Self {
+        Self {
+            frame: self.frame.flip_h(),
+            ..self
+        }
+    }
+
+    pub fn flip_v(self) -> Self {
+        Self {
+            frame: self.frame.flip_v(),
+            ..self
+        }
+    }
+
+    pub fn sub(self, origin: Point, rows: usize, columns: usize) -> Self {
+        Self {
+            frame: self.frame.sub(origin, rows, columns),
+            ..self
+        }
+    }
+}
+
+impl<T> std::ops::Index<Point> for GridViewMut<'_, T> {
+    type Output = T;
+    fn index(&self, point: Point) -> &Self::Output {
+        &self.grid[self.frame.point(point)]
+    }
+}
+
+impl<T> std::ops::IndexMut<Point> for GridViewMut<'_, T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        &mut self.grid[self.frame.point(point)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transforms() {
+        let grid = "abc\ndef".parse::<Grid2D<char>>().unwrap();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_h().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_v().to_string(), "def\nabc");
+
+        let around = grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+        assert_eq!(around, grid.view());
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.flip_h().flip_v());
+        assert_eq!(grid.rotate_ccw().to_grid(), "cf\nbe\nad".parse().unwrap());
+
+        let rotated = grid.rotate_cw();
+        assert_eq!(rotated.sub((1, 0), 2, 2).to_string(), "eb\nfc");
+        assert_eq!(rotated.at((2, 1)), Some(&'c'));
+        assert_eq!(rotated.at((0, 2)), None);
+        assert_eq!(rotated.column(1).collect::<String>(), "abc");
+    }
+
+    #[test]
+    fn write_through() {
+        let mut grid = "abc\ndef".parse::<Grid2D<char>>().unwrap();
+        let mut rotated = grid.view_mut().rotate_ccw();
+        rotated[(0, 0)] = 'x';
+        *rotated.at_mut((2, 1)).unwrap() = 'y';
+        assert_eq!(grid.to_string(), "abx\nyef");
+    }
+
+    #[test]
+    fn pattern_search() {
+        let grid = "#...\n.#..\n#.#.\n...#".parse::<Grid2D<char>>().unwrap();
+        let pattern = "#.\n.#".parse::<Grid2D<char>>().unwrap();
+
+        let found: Vec<_> = grid
+            .view()
+            .windows(2, 2)
+            .filter(|(_, window)| *window == pattern.view())
+            .map(|(origin, _)| origin)
+            .collect();
+        assert_eq!(found, vec![(0, 0), (1, 1), (2, 2)]);
+        // Mirrored, the pattern only shows up where the diagonal turns back.
+        assert_eq!(
+            grid.view()
+                .windows(2, 2)
+                .filter(|(_, window)| *window == pattern.flip_h())
+                .count(),
+            1
+        );
+    }
+}
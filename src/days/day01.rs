@@ -1,8 +1,11 @@
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
 pub mod part1 {
     /// Given a string with digits, find the first and the last
     /// occurrence of a digit and combine them to form a two digit
-    /// number.
-    pub fn parse_digits(s: &str) -> u32 {
+    /// number, if there's a digit at all.
+    pub fn parse_digits(s: &str) -> Option<u32> {
         let mut first_digit = None;
         let mut last_digit = None;
 
@@ -16,7 +19,7 @@ pub mod part1 {
             }
         }
 
-        first_digit.unwrap() * 10 + last_digit.unwrap()
+        Some(first_digit? * 10 + last_digit?)
     }
 }
 
@@ -24,8 +27,8 @@ pub mod part2 {
     /// Given a string with digits or the English word
     /// representation of the digits, find the first
     /// and the last occurrence of a digit and combine
-    /// them to form a two digit number.
-    pub fn parse_digits(s: &str) -> u32 {
+    /// them to form a two digit number, if there's a digit at all.
+    pub fn parse_digits(s: &str) -> Option<u32> {
         let mut first_digit = None;
         let mut last_digit = None;
 
@@ -33,7 +36,7 @@ pub mod part2 {
             "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
         ];
 
-        for (idx, char) in s.chars().enumerate() {
+        for (idx, char) in s.char_indices() {
             // Either a digit
             if char.is_ascii_digit() {
                 // Only record the first digit if not already recorded.
@@ -58,32 +61,42 @@ pub mod part2 {
             }
         }
 
-        first_digit.unwrap() * 10 + last_digit.unwrap()
+        Some(first_digit? * 10 + last_digit?)
     }
 }
 
-fn solve_part1(data: &str) {
-    let mut sum = 0;
-    for line in data.lines() {
-        let line_res = part1::parse_digits(line);
-        // eprintln!("res: {line_res}, line: {line}");
-        sum += line_res
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Input = Vec<String>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        if let Some(line) = data
+            .lines()
+            .find(|line| part2::parse_digits(line).is_none())
+        {
+            return Err(AocError::InvalidInput(format!(
+                "there's no digit on the line {line:?}"
+            )));
+        }
+        Ok(data.lines().map(String::from).collect())
     }
-    println!("part 1: {sum}")
-}
 
-fn solve_part2(data: &str) {
-    let mut sum = 0;
-    for line in data.lines() {
-        let line_res = part2::parse_digits(line);
-        // eprintln!("res: {line_res}, line: {line}");
-        sum += line_res
+    fn part1(input: &Self::Input) -> Answer {
+        // The digits could all be spelled out, as in the example for part 2.
+        solve_part1(input).map_or(Answer::Unsolved, Answer::from)
     }
-    println!("part 2: {sum}")
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+pub fn solve_part1(lines: &[String]) -> Option<u32> {
+    lines.iter().map(|line| part1::parse_digits(line)).sum()
 }
 
-pub fn main() {
-    let data = include_str!("../../data/01.in");
-    solve_part1(data);
-    solve_part2(data);
+pub fn solve_part2(lines: &[String]) -> Option<u32> {
+    lines.iter().map(|line| part2::parse_digits(line)).sum()
 }
@@ -0,0 +1,122 @@
+use crate::error::{AocError, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub type Set = std::collections::HashMap<String, u32>;
+pub type Game = (u32, Vec<Set>);
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    type Input = Vec<Game>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(data
+            .lines()
+            .map(|line| parse_cubes(line).map_err(|err| err.locate(data, line)))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve_part2(input).into()
+    }
+}
+
+/// Parse the descriptions of the sets drawn in each game into concrete structures.
+pub fn parse_cubes(s: &str) -> Result<Game, ParseError> {
+    let malformed = || ParseError::new("a game like `Game 1: 3 blue, 4 red; 1 red`", s);
+    let (game_with_index, rest) = s.split_once(':').ok_or_else(malformed)?;
+    let index: u32 = game_with_index
+        .strip_prefix("Game ")
+        .and_then(|index| index.parse().ok())
+        .ok_or_else(|| ParseError::new("a game like `Game 1`", game_with_index))?;
+    let mut all_sets = vec![];
+
+    for set in rest.split(';') {
+        let set = set.trim();
+        let items = set.split(',');
+        let mut current = Set::default();
+        for item in items {
+            let item = item.trim();
+            let malformed = || ParseError::new("a number of cubes like `3 blue`", item);
+            let (num, color) = item.split_once(' ').ok_or_else(malformed)?;
+            let num: u32 = num.parse().map_err(|_| malformed())?;
+            current.insert(color.to_string(), num);
+        }
+        all_sets.push(current);
+    }
+
+    Ok((index, all_sets))
+}
+
+pub mod part1 {
+    use super::Game;
+
+    pub fn solve_part1(games: &[Game]) -> u32 {
+        let mut allowed_cubes = std::collections::HashMap::new();
+        allowed_cubes.insert("red", 12);
+        allowed_cubes.insert("green", 13);
+        allowed_cubes.insert("blue", 14);
+
+        games
+            .iter()
+            .filter_map(|(idx, sets)| {
+                for set in sets.iter() {
+                    // Can this set be extracted given the current cubes we have?
+                    for (color, frequency) in set {
+                        if !allowed_cubes.contains_key(color.as_str())
+                            || allowed_cubes[color.as_str()] < *frequency
+                        {
+                            return None;
+                        }
+                    }
+                }
+                Some(*idx)
+            })
+            .sum()
+    }
+}
+
+pub mod part2 {
+    use super::Game;
+
+    pub fn solve_part2(games: &[Game]) -> u32 {
+        games
+            .iter()
+            .map(|(_, sets)| {
+                let mut result_map = std::collections::HashMap::<String, _>::new();
+                for set in sets.iter() {
+                    for (color, frequency) in set {
+                        result_map
+                            .entry(color.into())
+                            .and_modify(|freq| *freq = (*frequency).max(*freq))
+                            .or_insert(*frequency);
+                    }
+                }
+                result_map.values().product::<u32>()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        let err = Day02::parse("Game 1: 3 blue\nGame 2: x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "malformed input: expected a number of cubes like `3 blue`, found \"x\" at line 2, column 9
+  |
+2 | Game 2: x
+  |         ^"
+        );
+        assert!(parse_cubes("Gaem 1: 3 blue").is_err());
+    }
+}
@@ -1,14 +1,25 @@
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
 use regex::Regex;
 use std::collections::HashSet;
 
-fn main() {
-    let data = include_str!("../../data/03.in");
+pub struct Day03;
 
-    let res1 = part1::solve_part1(data);
-    let res2 = part2::solve_part2(data);
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    type Input = Grid;
 
-    println!("part 1: {res1}");
-    println!("part 2: {res2}");
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(Grid::new(&data.lines().collect::<Vec<_>>()))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::solve_part2(input).into()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -132,8 +143,9 @@ impl NumberIsland {
 
 #[cfg(test)]
 mod tests {
-    use crate::part1;
-    use crate::part2;
+    use super::part1;
+    use super::part2;
+    use super::{Day03, Solution};
 
     #[test]
     fn test_smol_data() {
@@ -147,16 +159,16 @@ mod tests {
 ......755.
 ...$.*....
 .664.598.."#;
-        assert_eq!(part1::solve_part1(data), 4361);
-        assert_eq!(part2::solve_part2(data), 467835);
+        let grid = Day03::parse(data).unwrap();
+        assert_eq!(part1::solve_part1(&grid), 4361);
+        assert_eq!(part2::solve_part2(&grid), 467835);
     }
 }
 
-mod part1 {
+pub mod part1 {
     use super::Grid;
 
-    pub fn solve_part1(data: &str) -> usize {
-        let grid = Grid::new(&data.lines().collect::<Vec<_>>());
+    pub fn solve_part1(grid: &Grid) -> usize {
         let numbers = grid.scan_numbers();
 
         let num_rows = grid.0.len();
@@ -178,12 +190,11 @@ mod part1 {
     }
 }
 
-mod part2 {
+pub mod part2 {
     use super::Grid;
     use std::collections::HashSet;
 
-    pub fn solve_part2(data: &str) -> usize {
-        let grid = Grid::new(&data.lines().collect::<Vec<_>>());
+    pub fn solve_part2(grid: &Grid) -> usize {
         let numbers = grid.scan_numbers();
 
         let num_rows = grid.0.len();
@@ -1,13 +1,28 @@
-pub fn main() {
-    let data = include_str!("../../data/05.in");
+use crate::error::{AocError, ParseError};
+use crate::solution::{Answer, Solution};
 
-    println!("part 1: {}", solve_part1(data));
-    println!("part 2: {}", solve_part2(data));
-}
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    type Input = (Vec<u64>, Almanac);
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_seeds_and_almanac(data)?)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (seeds, almanac) = input;
+        solve_part1(seeds, almanac).into()
+    }
 
-pub fn solve_part1(data: &str) -> usize {
-    let (seeds, almanac) = parse_seeds_and_almanac(data);
+    fn part2(input: &Self::Input) -> Answer {
+        let (seeds, almanac) = input;
+        solve_part2(seeds, almanac).into()
+    }
+}
 
+pub fn solve_part1(seeds: &[u64], almanac: &Almanac) -> usize {
     seeds
         .iter()
         .map(|seed| almanac.propagate_seed(*seed as usize))
@@ -15,9 +30,7 @@ pub fn solve_part1(data: &str) -> usize {
         .unwrap()
 }
 
-pub fn solve_part2(data: &str) -> usize {
-    let (seeds, almanac) = parse_seeds_and_almanac(data);
-
+pub fn solve_part2(seeds: &[u64], almanac: &Almanac) -> usize {
     seeds
         .chunks_exact(2)
         .map(|pair| {
@@ -34,46 +47,16 @@ pub fn solve_part2(data: &str) -> usize {
 
 #[cfg(test)]
 mod tests {
+    use super::parse_seeds_and_almanac;
     use super::solve_part1;
     use super::solve_part2;
 
     #[test]
     fn test_smol_data() {
-        let data = r#"seeds: 79 14 55 13
-
-seed-to-soil map:
-50 98 2
-52 50 48
-
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
-
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-
-water-to-light map:
-88 18 7
-18 25 70
-
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
-
-temperature-to-humidity map:
-0 69 1
-1 0 69
-
-humidity-to-location map:
-60 56 37
-56 93 4"#;
-        assert_eq!(solve_part1(data), 35);
-        assert_eq!(solve_part2(data), 46);
+        let data = include_str!("../../fixtures/examples/05-1.in");
+        let (seeds, almanac) = parse_seeds_and_almanac(data).unwrap();
+        assert_eq!(solve_part1(&seeds, &almanac), 35);
+        assert_eq!(solve_part2(&seeds, &almanac), 46);
     }
 }
 
@@ -85,19 +68,20 @@ pub struct Mapping {
 }
 
 impl FromStr for Mapping {
-    type Err = std::io::Error;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let nums = s.split_whitespace().collect::<Vec<_>>();
-        if nums.len() != 3 {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                "expected exactly 3 numbers for a range description.",
-            ));
-        }
+        let malformed = || ParseError::new("exactly 3 numbers for a range description", s);
+        let nums = s
+            .split_whitespace()
+            .map(|num| num.parse().map_err(|_| malformed()))
+            .collect::<Result<Vec<usize>, _>>()?;
+        let [destination_start, source_start, range] = nums[..] else {
+            return Err(malformed());
+        };
         Ok(Self {
-            destination_start: nums[0].parse().unwrap(),
-            source_start: nums[1].parse().unwrap(),
-            range: nums[2].parse().unwrap(),
+            destination_start,
+            source_start,
+            range,
         })
     }
 }
@@ -121,7 +105,7 @@ impl Category {
 /// Given two ranges, compute its intersection range, if exists.
 #[inline(always)]
 pub fn find_common_interval(range1: Range<isize>, range2: Range<isize>) -> Option<Range<isize>> {
-    if range2.start > range1.end || range1.start > range2.end {
+    if range2.start >= range1.end || range1.start >= range2.end {
         None
     } else {
         let start = range1.start.max(range2.start);
@@ -142,7 +126,7 @@ impl Almanac {
         for category in self.0.iter() {
             for mapping in &category.mappings {
                 if mapping.source_start <= current_value
-                    && current_value <= mapping.source_start + mapping.range
+                    && current_value < mapping.source_start + mapping.range
                 {
                     current_value =
                         mapping.destination_start + (current_value - mapping.source_start);
@@ -161,43 +145,47 @@ impl Almanac {
     /// anyway in the next level.
     pub fn propagate_seed_range(&self, seeds: Range<isize>) -> Vec<Range<isize>> {
         let mut current_ranges = vec![seeds];
-        let total = self.0.len();
 
-        for (idx, category) in self.0.iter().enumerate() {
-            if idx == total - 1 {
-                return current_ranges;
-            }
+        for category in self.0.iter() {
             let mut next_ranges = vec![];
 
-            // Attempt to split the current ranges into mapped ranges.
-            for range in &current_ranges {
-                let mut some_mapping_found = false;
+            for range in current_ranges {
+                // The pieces of the range that none of the mappings have claimed so far.
+                // Like a single seed, every piece goes through the first mapping that covers it.
+                let mut unmapped = vec![range];
+
                 for mapping in &category.mappings {
                     let shift = mapping.destination_start as isize - mapping.source_start as isize;
                     let mapping_range = mapping.source_start as isize
                         ..mapping.source_start as isize + mapping.range as isize;
 
-                    if let Some(common_interval) =
-                        find_common_interval(range.clone(), mapping_range)
-                    {
+                    let mut still_unmapped = vec![];
+                    for piece in unmapped {
+                        let Some(common_interval) =
+                            find_common_interval(piece.clone(), mapping_range.clone())
+                        else {
+                            still_unmapped.push(piece);
+                            continue;
+                        };
                         next_ranges
                             .push(common_interval.start + shift..common_interval.end + shift);
-                        some_mapping_found = true;
-                    }
-                }
 
-                // If no mappings were found, just carry forward the entire range as is.
-                if !some_mapping_found {
-                    next_ranges.push(range.clone());
+                        // The mapping may only cover the piece partially, so whatever
+                        // sticks out on either side is left for the other mappings.
+                        still_unmapped.extend(
+                            [
+                                piece.start..common_interval.start,
+                                common_interval.end..piece.end,
+                            ]
+                            .into_iter()
+                            .filter(|rest| !rest.is_empty()),
+                        );
+                    }
+                    unmapped = still_unmapped;
                 }
 
-                // It is possible that the mappings didn't cover the desired range
-                // entirely. In this case, carry forward the remaining intervals
-                // to the next category.
-
-                // Actual interval decomposition sounds really tricky to get right
-                // but I guess my test data doesn't have any ranges that cover the mapping
-                // only partially (over the non-mapped data), so I got lucky.
+                // Values that no mapping covers keep their number in the next category.
+                next_ranges.extend(unmapped);
             }
             current_ranges = next_ranges;
         }
@@ -205,31 +193,35 @@ impl Almanac {
     }
 }
 
-pub fn parse_seeds_and_almanac(data: &str) -> (Vec<u64>, Almanac) {
+pub fn parse_seeds_and_almanac(data: &str) -> Result<(Vec<u64>, Almanac), ParseError> {
     let lines = data.split("\n\n").collect::<Vec<_>>();
     let seeds: Vec<_> = lines[0]
-        .split(':')
-        .last()
-        .unwrap()
+        .strip_prefix("seeds:")
+        .ok_or_else(|| ParseError::new("the seeds like `seeds: 79 14 55 13`", lines[0]))?
         .split_whitespace()
-        .map(|num| num.parse::<u64>().unwrap())
-        .collect();
+        .map(|num| {
+            num.parse::<u64>()
+                .map_err(|_| ParseError::new("a seed number", num))
+        })
+        .collect::<Result<_, _>>()?;
 
     let mut almanac: Almanac = Almanac(Default::default());
 
     for &line in &lines[1..] {
-        let (_, mappings) = line.split_once(" map:").unwrap();
+        let (_, mappings) = line
+            .split_once(" map:")
+            .ok_or_else(|| ParseError::new("a map like `seed-to-soil map:`", line))?;
 
         let mappings: Vec<_> = mappings
             .trim()
             .split('\n')
-            .map(|s| s.parse::<Mapping>().unwrap())
-            .collect();
+            .map(|s| s.parse::<Mapping>())
+            .collect::<Result<_, _>>()?;
 
         let category = Category { mappings };
 
         almanac.0.push(category);
     }
 
-    (seeds, almanac)
+    Ok((seeds, almanac))
 }
@@ -0,0 +1,138 @@
+use crate::error::{AocError, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    type Input = Races;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_races(data)?)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+}
+
+/// Find out the number of integer points that satisfy:
+///
+/// (max_time - x) * x > min_distance
+/// where x in [0..max_time]
+///
+/// The quadratic formula gives the interval, but floats lose precision for
+/// large races, so its lower end is only a starting point that gets nudged
+/// onto the first winning integer. The wins are symmetric around half the time,
+/// which is also where the farthest distance is, so if that doesn't win nothing does.
+#[inline(always)]
+pub fn count_wins(max_time: u64, min_distance: u64) -> usize {
+    let wins = |x: u64| (max_time - x) as u128 * x as u128 > min_distance as u128;
+
+    let peak = max_time / 2;
+    if !wins(peak) {
+        return 0;
+    }
+
+    let discriminant = (max_time as f64).powf(2.0) - 4.0 * min_distance as f64;
+    let estimate = 0.5 * (max_time as f64 - discriminant.max(0.0).sqrt());
+    let mut lower_bound = (estimate.floor().max(0.0) as u64).min(peak);
+    while lower_bound > 0 && wins(lower_bound - 1) {
+        lower_bound -= 1;
+    }
+    while !wins(lower_bound) {
+        lower_bound += 1;
+    }
+
+    let upper_bound = max_time - lower_bound;
+    (upper_bound - lower_bound + 1) as usize
+}
+
+/// The durations of the races and the record distances for them.
+pub type Races = (Vec<u64>, Vec<u64>);
+
+pub fn parse_races(data: &str) -> Result<Races, ParseError> {
+    let mut lines = data.lines();
+    let mut numbers = |label: &str| -> Result<Vec<u64>, ParseError> {
+        let expected = format!("a line like `{label}  7  15  30`");
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::new(&expected, "the end of the input"))?;
+        let numbers = line
+            .strip_prefix(label)
+            .ok_or_else(|| ParseError::new(&expected, line).locate(data, line))?
+            .split_whitespace()
+            .map(|s| {
+                s.parse()
+                    .map_err(|_| ParseError::new("a number", s).locate(data, line))
+            })
+            .collect::<Result<Vec<u64>, _>>()?;
+        match numbers.is_empty() {
+            true => Err(ParseError::new(expected, line).locate(data, line)),
+            false => Ok(numbers),
+        }
+    };
+
+    let times = numbers("Time:")?;
+    let distances = numbers("Distance:")?;
+    if times.len() != distances.len() {
+        return Err(ParseError::new(
+            format!("a distance for each of the {} races", times.len()),
+            format!("{} distances", distances.len()),
+        ));
+    }
+    Ok((times, distances))
+}
+
+pub fn solve_part1(races: &Races) -> usize {
+    let (times, distances) = races;
+    times
+        .iter()
+        .zip(distances.iter())
+        .map(|(&max_time, &min_distance)| count_wins(max_time, min_distance))
+        .product()
+}
+
+/// The numbers were never meant to be separated in the first place,
+/// so join all of their digits together into a single race.
+pub fn solve_part2(races: &Races) -> usize {
+    let (times, distances) = races;
+    let join_digits = |numbers: &[u64]| -> u64 {
+        numbers
+            .iter()
+            .map(|number| number.to_string())
+            .collect::<String>()
+            .parse()
+            .unwrap()
+    };
+    count_wins(join_digits(times), join_digits(distances))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_races, solve_part1, solve_part2};
+
+    #[test]
+    fn test_smol_data() {
+        let data = r"Time:      7  15   30
+Distance:  9  40  200";
+
+        let races = parse_races(data).unwrap();
+        assert_eq!(solve_part1(&races), 288);
+        assert_eq!(solve_part2(&races), 71503);
+    }
+
+    #[test]
+    fn test_big_data() {
+        let data = r"Time:        46     68     98     66
+Distance:   358   1054   1807   1080";
+
+        let races = parse_races(data).unwrap();
+        assert_eq!(solve_part1(&races), 138915);
+        assert_eq!(solve_part2(&races), 27340847);
+    }
+}
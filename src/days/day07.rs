@@ -1,20 +1,30 @@
-pub fn main() {
-    let data = include_str!("../../data/07.in");
-    println!(
-        "part 1: {}",
-        solve(data, |hand1, hand2| hand1.cmp_part1(hand2))
-    );
-    println!(
-        "part 2: {}",
-        solve(data, |hand1, hand2| hand1.cmp_part2(hand2))
-    );
+use crate::error::{AocError, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    type Input = Vec<Hand>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(data.lines().map(Hand::new).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve(input, |hand1, hand2| hand1.cmp_part1(hand2)).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve(input, |hand1, hand2| hand1.cmp_part2(hand2)).into()
+    }
 }
 
-pub fn solve<F>(data: &str, cmp: F) -> usize
+pub fn solve<F>(hands: &[Hand], cmp: F) -> usize
 where
     F: Fn(&Hand, &Hand) -> Option<Ordering>,
 {
-    let mut hands: Vec<_> = data.lines().map(Hand::new).collect();
+    let mut hands = hands.to_vec();
 
     hands.sort_by(|hand1, hand2| cmp(hand1, hand2).unwrap());
 
@@ -83,7 +93,7 @@ impl std::fmt::Display for Card {
 }
 
 impl TryFrom<char> for Card {
-    type Error = std::io::Error;
+    type Error = ParseError;
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
             'A' => Ok(Card::A),
@@ -91,17 +101,17 @@ impl TryFrom<char> for Card {
             'Q' => Ok(Card::Q),
             'J' => Ok(Card::J),
             'T' => Ok(Card::T),
-            digit @ '0'..='9' => Ok(Card::Num(digit.to_digit(10).unwrap() as u8)),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                "unknown card type.",
+            digit @ '2'..='9' => Ok(Card::Num(digit.to_digit(10).unwrap() as u8)),
+            _ => Err(ParseError::new(
+                "a card out of `AKQJT98765432`",
+                value.to_string(),
             )),
         }
     }
 }
 
 impl Hand {
-    fn cmp_part1(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    pub fn cmp_part1(&self, other: &Self) -> Option<std::cmp::Ordering> {
         let own_kind = self.kind_part1();
         let other_kind = other.kind_part1();
 
@@ -118,7 +128,7 @@ impl Hand {
         Some(std::cmp::Ordering::Equal)
     }
 
-    fn cmp_part2(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    pub fn cmp_part2(&self, other: &Self) -> Option<std::cmp::Ordering> {
         let own_kind = self.kind_part2();
         let other_kind = other.kind_part2();
 
@@ -150,7 +160,7 @@ impl Hand {
         }
         if self.counts.len() == 2 {
             let counts = self.counts.iter().collect::<Vec<_>>();
-            let first = *counts.get(0).unwrap();
+            let first = *counts.first().unwrap();
 
             if *first.1 == 1 || *first.1 == 4 {
                 return HandKind::FourOfAKind;
@@ -180,7 +190,7 @@ impl Hand {
         if num_wildcards == 5 {
             return HandKind::FiveOfAKind;
         }
-        return match self.counts.len() {
+        match self.counts.len() {
             1 => HandKind::FiveOfAKind,
             2 => {
                 if num_wildcards > 0 {
@@ -188,7 +198,7 @@ impl Hand {
                 }
 
                 let counts = self.counts.iter().collect::<Vec<_>>();
-                let first = *counts.get(0).unwrap();
+                let first = *counts.first().unwrap();
 
                 if *first.1 == 1 || *first.1 == 4 {
                     HandKind::FourOfAKind
@@ -236,20 +246,21 @@ impl Hand {
                 HandKind::OnePair
             }
             _ => unreachable!("welp. an uncovered case? unlikely: {}", self),
-        };
+        }
     }
 }
 
 impl Hand {
-    pub fn new(s: &str) -> Self {
-        let (hand_str, bid) = s.split_once(' ').unwrap();
+    pub fn new(s: &str) -> Result<Self, ParseError> {
+        let malformed = || ParseError::new("five cards and a bid like `32T3K 765`", s);
+        let (hand_str, bid) = s.split_once(' ').ok_or_else(malformed)?;
         let data: [Card; 5] = hand_str
             .chars()
-            .map(|c| c.try_into().unwrap())
-            .collect::<Vec<_>>()
+            .map(Card::try_from)
+            .collect::<Result<Vec<_>, _>>()?
             .try_into()
-            .unwrap();
-        let bid = bid.parse().unwrap();
+            .map_err(|_| malformed())?;
+        let bid = bid.parse().map_err(|_| malformed())?;
 
         let mut counts: HashMap<Card, usize> = HashMap::default();
 
@@ -257,16 +268,17 @@ impl Hand {
             counts.entry(*card).and_modify(|c| *c += 1).or_insert(1);
         }
 
-        Self {
+        Ok(Self {
             cards: data,
             bid,
             counts,
-        }
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{Day07, Solution};
 
     #[test]
     fn test_smol_data() {
@@ -275,13 +287,14 @@ T55J5 684
 KK677 28
 KTJJT 220
 QQQJA 483";
+        let hands = Day07::parse(data).unwrap();
 
         assert_eq!(
-            super::solve(data, |hand1, hand2| hand1.cmp_part1(hand2)),
+            super::solve(&hands, |hand1, hand2| hand1.cmp_part1(hand2)),
             6440
         );
         assert_eq!(
-            super::solve(data, |hand1, hand2| hand1.cmp_part2(hand2)),
+            super::solve(&hands, |hand1, hand2| hand1.cmp_part2(hand2)),
             5905
         );
     }
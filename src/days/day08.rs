@@ -0,0 +1,177 @@
+use crate::error::{AocError, ParseError};
+use crate::math::lcm;
+use crate::solution::{Answer, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    type Input = (Vec<usize>, Mapping);
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        let (seq, mapping) = parse_sequence_and_mappings(data)?;
+
+        // Otherwise we'd walk off the map, or never get anywhere at all.
+        if let Some(target) = mapping
+            .values()
+            .flatten()
+            .find(|target| !mapping.contains_key(*target))
+        {
+            return Err(AocError::InvalidInput(format!(
+                "the node {target} is a destination but isn't on the map"
+            )));
+        }
+        if !mapping.keys().any(|node| node.ends_with('A')) {
+            return Err(AocError::InvalidInput(
+                "there are no starting nodes ending in A".to_string(),
+            ));
+        }
+        if seq.is_empty() {
+            return Err(AocError::InvalidInput(
+                "there are no instructions".to_string(),
+            ));
+        }
+        Ok((seq, mapping))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (seq, mapping) = input;
+        // The example for part 2 has no AAA.
+        solve_part1(seq, mapping).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (seq, mapping) = input;
+        solve_part2(seq, mapping).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+/// Compute the minimum number of steps needed to traverse from "AAA" to "ZZZ"
+/// given we could only follow a never-ending cycle of instructions.
+pub fn solve_part1(seq: &[usize], mapping: &Mapping) -> Option<usize> {
+    count_steps(seq, mapping, "AAA", |node| node == "ZZZ")
+}
+
+/// Compute the least common multiple of the minimum number of steps needed for each starting node to reach an ending node
+/// following left/right from a never-ending cycle of instructions.
+pub fn solve_part2(seq: &[usize], mapping: &Mapping) -> Option<usize> {
+    mapping
+        .keys()
+        .filter(|&node| node.ends_with('A'))
+        .map(|node| count_steps(seq, mapping, node, |node| node.ends_with('Z')))
+        .reduce(|steps, other| Some(lcm(steps?, other?)))?
+}
+
+pub type Mapping = std::collections::HashMap<String, [String; 2]>;
+
+/// Traverse the mapping following the left/right instructions from the sequence
+/// and stop when the start node satisfies the ending condition.
+/// Gives up on reaching a node that isn't in the mapping.
+pub fn count_steps<F>(
+    seq: &[usize],
+    mapping: &Mapping,
+    start_node: &str,
+    end_cond: F,
+) -> Option<usize>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut start_node = start_node;
+    let mut counter = 0;
+
+    for &selection in seq.iter().cycle() {
+        if end_cond(start_node) {
+            break;
+        }
+        start_node = mapping.get(start_node)?[selection].as_str();
+        counter += 1;
+    }
+
+    Some(counter)
+}
+
+pub fn parse_sequence_and_mappings(data: &str) -> Result<(Vec<usize>, Mapping), ParseError> {
+    let (sequence, mappings) = data.split_once("\n\n").ok_or_else(|| {
+        ParseError::new(
+            "the instructions and the nodes separated by a blank line",
+            data.lines().next().unwrap_or_default(),
+        )
+    })?;
+
+    let sequence = sequence
+        .trim()
+        .chars()
+        .map(|c| match c {
+            'R' => Ok(1usize),
+            'L' => Ok(0),
+            _ => Err(ParseError::new("only R or L", c.to_string())),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mappings: Mapping = mappings
+        .lines()
+        .map(|line| {
+            let malformed = || ParseError::new("a node like `AAA = (BBB, CCC)`", line);
+            let (start, rest) = line.split_once(" = ").ok_or_else(malformed)?;
+            let (left, right) = rest
+                .strip_prefix('(')
+                .and_then(|rest| rest.strip_suffix(')'))
+                .and_then(|rest| rest.split_once(", "))
+                .ok_or_else(malformed)?;
+            Ok((start.to_string(), [left.to_string(), right.to_string()]))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((sequence, mappings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_sequence_and_mappings, solve_part1, solve_part2};
+
+    #[test]
+    fn test_smol_data() {
+        let data = r"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+        let (seq, mapping) = parse_sequence_and_mappings(data).unwrap();
+        assert_eq!(solve_part1(&seq, &mapping), Some(2));
+        assert_eq!(solve_part2(&seq, &mapping), Some(2));
+    }
+
+    #[test]
+    fn test_part1_data2() {
+        let data = r"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        let (seq, mapping) = parse_sequence_and_mappings(data).unwrap();
+        assert_eq!(solve_part1(&seq, &mapping), Some(6));
+        assert_eq!(solve_part2(&seq, &mapping), Some(6));
+    }
+
+    #[test]
+    fn test_part2_data1() {
+        let data = r"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        let (seq, mapping) = parse_sequence_and_mappings(data).unwrap();
+        assert_eq!(solve_part1(&seq, &mapping), None);
+        assert_eq!(solve_part2(&seq, &mapping), Some(6));
+    }
+}
@@ -1,27 +1,27 @@
+use crate::error::{AocError, ParseError};
+use crate::solution::{Answer, Solution};
+
 pub type Sequence = std::collections::LinkedList<isize>;
 
-pub fn main() {
-    let data = include_str!("../../data/09.in");
-    let sequences: Vec<Sequence> = data.lines().map(parse_sequence).collect();
-    println!("part 1: {}", solve_part1(&sequences));
-    println!("part 2: {}", solve_part2(&sequences));
-    // assert!(submit(9, 1, &solve_part1(&sequences).to_string()).unwrap());
-    // assert!(submit(9, 2, &solve_part2(&sequences).to_string()).unwrap());
-}
+pub struct Day09;
 
-#[cfg(test)]
-mod tests {
-    use super::{parse_sequence, solve_part1, solve_part2, Sequence};
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    type Input = Vec<Sequence>;
 
-    #[test]
-    fn test_smol_data() {
-        let data = r"0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45";
-        let sequences: Vec<Sequence> = data.lines().map(parse_sequence).collect();
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(data
+            .lines()
+            .map(|line| parse_sequence(line).map_err(|err| err.locate(data, line)))
+            .collect::<Result<_, _>>()?)
+    }
 
-        assert_eq!(solve_part1(&sequences), 114);
-        assert_eq!(solve_part2(&sequences), 2);
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
     }
 }
 
@@ -57,11 +57,15 @@ pub fn predict_previous(mut stack: Vec<Sequence>) -> isize {
     *stack[0].front().unwrap()
 }
 
-pub fn parse_sequence(line: &str) -> Sequence {
-    line.split_whitespace()
-        .map(|s| s.parse())
-        .collect::<Result<Sequence, _>>()
-        .unwrap()
+pub fn parse_sequence(line: &str) -> Result<Sequence, ParseError> {
+    let sequence = line
+        .split_whitespace()
+        .map(|s| s.parse().map_err(|_| ParseError::new("a number", s)))
+        .collect::<Result<Sequence, _>>()?;
+    match sequence.is_empty() {
+        true => Err(ParseError::new("a sequence of numbers", line)),
+        false => Ok(sequence),
+    }
 }
 
 pub fn build_stack(sequence: &Sequence) -> Vec<Sequence> {
@@ -86,3 +90,23 @@ pub fn diffs(seq: &Sequence) -> Sequence {
         .map(|(&next, &prev)| next - prev)
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_sequence, solve_part1, solve_part2, Sequence};
+
+    #[test]
+    fn test_smol_data() {
+        let data = r"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        let sequences: Vec<Sequence> = data
+            .lines()
+            .map(parse_sequence)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(solve_part1(&sequences), 114);
+        assert_eq!(solve_part2(&sequences), 2);
+    }
+}
@@ -0,0 +1,344 @@
+use crate::data_structures::Grid2D;
+use crate::error::{AocError, ParseError};
+use crate::solution::{Answer, Solution};
+use colored::Colorize;
+use log::{debug, log_enabled, Level};
+use std::collections::binary_heap::BinaryHeap;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = Graph;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        parse_graph(data)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord(isize, isize);
+
+const NORTH: Coord = Coord(-1, 0);
+const SOUTH: Coord = Coord(1, 0);
+const WEST: Coord = Coord(0, -1);
+const EAST: Coord = Coord(0, 1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pipe {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Ground,
+    Start,
+}
+
+impl Pipe {
+    /// The shifts to the cells this pipe connects to. The start is replaced
+    /// by the pipe under it while parsing, so it doesn't connect to anything.
+    fn openings(self) -> &'static [Coord] {
+        match self {
+            Self::Vertical => &[NORTH, SOUTH],
+            Self::Horizontal => &[WEST, EAST],
+            Self::NorthEast => &[NORTH, EAST],
+            Self::NorthWest => &[NORTH, WEST],
+            Self::SouthWest => &[SOUTH, WEST],
+            Self::SouthEast => &[SOUTH, EAST],
+            Self::Ground | Self::Start => &[],
+        }
+    }
+
+    fn opens_to(self, shift: Coord) -> bool {
+        self.openings().contains(&shift)
+    }
+
+    /// The pipe that connects the two given shifts, if there is one.
+    fn connecting(first: Coord, second: Coord) -> Option<Self> {
+        [
+            Self::Vertical,
+            Self::Horizontal,
+            Self::NorthEast,
+            Self::NorthWest,
+            Self::SouthWest,
+            Self::SouthEast,
+        ]
+        .into_iter()
+        .find(|pipe| first != second && pipe.opens_to(first) && pipe.opens_to(second))
+    }
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = ParseError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(Self::Vertical),
+            '-' => Ok(Self::Horizontal),
+            'L' => Ok(Self::NorthEast),
+            'J' => Ok(Self::NorthWest),
+            '7' => Ok(Self::SouthWest),
+            'F' => Ok(Self::SouthEast),
+            '.' => Ok(Self::Ground),
+            'S' => Ok(Self::Start),
+            _ => Err(ParseError::new("one of `|-LJ7F.S`", value.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for Pipe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Vertical => write!(f, "|"),
+            Self::Horizontal => write!(f, "-"),
+            Self::NorthEast => write!(f, "L"),
+            Self::NorthWest => write!(f, "J"),
+            Self::SouthWest => write!(f, "7"),
+            Self::SouthEast => write!(f, "F"),
+            Self::Ground => write!(f, "."),
+            Self::Start => write!(f, "S"),
+        }
+    }
+}
+
+/// The pipes, with the start already replaced by the pipe under it.
+#[derive(Debug, Clone)]
+pub struct Graph {
+    pipes: Grid2D<Pipe>,
+    start: Coord,
+}
+
+impl std::ops::Add for Coord {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl std::ops::Sub for Coord {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl std::ops::Neg for Coord {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self(-self.0, -self.1)
+    }
+}
+
+impl Graph {
+    pub fn get(&self, coord: Coord) -> Option<Pipe> {
+        self.pipes.at((coord.0, coord.1)).copied()
+    }
+
+    pub fn start(&self) -> Coord {
+        self.start
+    }
+
+    fn mark_non_loop_as_ground(&mut self, loop_indices: &HashMap<Coord, i32>) {
+        for ((row_idx, col_idx), value) in self.pipes.iter_mut() {
+            if !loop_indices.contains_key(&Coord(row_idx as isize, col_idx as isize)) {
+                *value = Pipe::Ground;
+            }
+        }
+    }
+
+    /// The shifts from the given cell to the pipes around it that open towards it.
+    fn shifts_to_connected_pipes(&self, coord: Coord) -> Vec<Coord> {
+        [NORTH, SOUTH, WEST, EAST]
+            .into_iter()
+            .filter(|&shift| {
+                self.get(coord + shift)
+                    .is_some_and(|pipe| pipe.opens_to(-shift))
+            })
+            .collect()
+    }
+
+    fn neighbors(&self, coord: Coord) -> Option<impl Iterator<Item = Coord> + '_> {
+        let pipe = self.get(coord)?;
+
+        Some(
+            pipe.openings()
+                .iter()
+                .filter(move |&&shift| self.is_valid(coord + shift))
+                .map(move |&shift| shift + coord)
+                .filter(move |pos| self.get(*pos) != Some(Pipe::Ground)),
+        )
+    }
+    pub fn is_valid(&self, coord: Coord) -> bool {
+        self.pipes.at((coord.0, coord.1)).is_some()
+    }
+
+    fn log_marked_cells(
+        &self,
+        inside: &[Coord],
+        outside: &[Coord],
+        loop_indices: &HashMap<Coord, i32>,
+    ) {
+        let mut graph = self.pipes.map(|pipe| pipe.to_string());
+        for coord in inside {
+            graph[(coord.0 as usize, coord.1 as usize)] = "I".green().to_string();
+        }
+        for coord in outside {
+            graph[(coord.0 as usize, coord.1 as usize)] = "O".red().to_string();
+        }
+        for coord in loop_indices.keys() {
+            let v = &mut graph[(coord.0 as usize, coord.1 as usize)];
+            *v = v.blue().to_string();
+        }
+
+        debug!("inside and outside the loop:\n{}", graph);
+    }
+
+    pub fn single_source_shortest_paths(&self, source: Coord) -> HashMap<Coord, i32> {
+        let mut distances = HashMap::new();
+        let mut queue = BinaryHeap::new();
+        queue.push((0, source));
+
+        while !queue.is_empty() {
+            let (dist, current) = queue.pop().unwrap();
+            distances.insert(current, -dist);
+
+            if let Some(neighbors) = self.neighbors(current).map(|it| it.collect::<Vec<_>>()) {
+                for neighbor in neighbors.iter() {
+                    if !distances.contains_key(neighbor) {
+                        queue.push((-(-dist + 1), *neighbor));
+                    }
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+pub fn parse_graph(data: &str) -> Result<Graph, AocError> {
+    let pipes: Grid2D<Pipe> = data.parse()?;
+    let starts = pipes.positions_of(&Pipe::Start).collect::<Vec<_>>();
+    let [(row_idx, col_idx)] = starts[..] else {
+        return Err(AocError::InvalidInput(
+            "there has to be exactly one starting position `S` on the map".to_string(),
+        ));
+    };
+    let start = Coord(row_idx as isize, col_idx as isize);
+    let mut graph = Graph { pipes, start };
+
+    // The start is part of the loop, so it has to be a pipe that connects to exactly two others.
+    let shape = match graph.shifts_to_connected_pipes(start)[..] {
+        [first, second] => Pipe::connecting(first, second),
+        _ => None,
+    };
+    let Some(shape) = shape else {
+        return Err(AocError::InvalidInput(
+            "the starting position `S` doesn't connect to exactly two pipes".to_string(),
+        ));
+    };
+    graph.pipes[(row_idx, col_idx)] = shape;
+    Ok(graph)
+}
+
+pub fn solve_part1(graph: &Graph) -> i32 {
+    let shortest_paths = graph.single_source_shortest_paths(graph.start());
+
+    shortest_paths.values().copied().max().unwrap()
+}
+
+pub fn solve_part2(graph: &Graph) -> usize {
+    let mut graph = graph.clone();
+    let shortest_paths = graph.single_source_shortest_paths(graph.start());
+    graph.mark_non_loop_as_ground(&shortest_paths);
+
+    let mut remaining_indices = HashSet::new();
+    let num_columns = graph.pipes.columns;
+    let num_rows = graph.pipes.rows;
+
+    for row_idx in 0..num_rows {
+        for col_idx in 0..num_columns {
+            if !shortest_paths.contains_key(&Coord(row_idx as isize, col_idx as isize)) {
+                remaining_indices.insert(Coord(row_idx as isize, col_idx as isize));
+            }
+        }
+    }
+
+    let mut inside = vec![];
+    let mut outside = vec![];
+
+    // Check every non-loop index to see whether it lies inside the loop or outside.
+    // Take the winding number approach by scanning a row from left to right
+    // and flipping inside/outside status for a cell based on the parity of walls
+    // encountered. Since we started outside the loop, an odd number of wall crossings
+    // imply we're inside the loop, and similarly, an even number of wall crossings imply
+    // we're outside the loop again.
+    let sum = remaining_indices
+        .iter()
+        .map(|&coord| {
+            if coord.1 == 0 {
+                outside.push(coord);
+                return 0;
+            }
+            // Count the north-facing wall crossings (i.e. '|' | 'J' | 'L') on the left of coord.
+            let left_strip = graph
+                .pipes
+                .row(coord.0 as usize)
+                .iter()
+                .take(coord.1 as usize);
+            let north_facing = left_strip.filter(|pipe| pipe.opens_to(NORTH)).count();
+            if north_facing % 2 == 1 {
+                inside.push(coord);
+                1
+            } else {
+                outside.push(coord);
+                0
+            }
+        })
+        .sum();
+
+    if log_enabled!(Level::Debug) {
+        // arghh, show the inside/outside as color-coded for debug.
+        graph.log_marked_cells(&inside, &outside, &shortest_paths);
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_graph;
+    use crate::error::AocError;
+
+    #[test]
+    fn parse_rejects_bad_maps() {
+        assert!(parse_graph(".....\n.S-7.\n.|.|.\n.L-J.\n.....").is_ok());
+        // Two starts, or none at all.
+        assert!(matches!(
+            parse_graph(".S-7.\n.|.|.\n.L-S."),
+            Err(AocError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            parse_graph("F-7\n|.|\nL-J"),
+            Err(AocError::InvalidInput(_))
+        ));
+        // A start that's only connected on one side.
+        assert!(matches!(
+            parse_graph("S-7\n..|\n..J"),
+            Err(AocError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            parse_graph("S-7\n|x|\nL-J"),
+            Err(AocError::Grid(_))
+        ));
+    }
+}
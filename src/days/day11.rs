@@ -1,22 +1,38 @@
-pub fn main() {
-    let data = include_str!("../../data/11.in");
+use crate::data_structures::{Grid2D, Grid2DParseError};
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
 
-    println!("part 1: {}", solve_part1(data));
-    println!("part 2: {}", solve_part2(data));
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = Grid;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(Grid::new(data)?)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct Grid(Vec<Vec<char>>);
+pub struct Grid(Grid2D<char>);
 pub type Coord = (isize, isize);
 
 impl Grid {
-    pub fn new(data: &str) -> Self {
-        Self(data.lines().map(|line| line.chars().collect()).collect())
+    pub fn new(data: &str) -> Result<Self, Grid2DParseError> {
+        Ok(Self(data.parse()?))
     }
 
     fn empty_rows(&self) -> std::collections::HashSet<usize> {
         self.0
-            .iter()
+            .iter_rows()
             .enumerate()
             .filter_map(|(row_idx, row)| {
                 if row.iter().all(|&c| c == '.') {
@@ -29,39 +45,24 @@ impl Grid {
     }
 
     fn empty_columns(&self) -> std::collections::HashSet<usize> {
-        let mut hset = std::collections::HashSet::new();
-
-        let num_rows = self.0.len();
-        let num_cols = self.0.get(0).unwrap().len();
-
-        for col_idx in 0..num_cols {
-            let mut is_empty = true;
-            for row_idx in 0..num_rows {
-                if *self.0.get(row_idx).unwrap().get(col_idx).unwrap() != '.' {
-                    is_empty = false;
-                    break;
+        self.0
+            .iter_columns()
+            .enumerate()
+            .filter_map(|(col_idx, mut column)| {
+                if column.all(|&c| c == '.') {
+                    Some(col_idx)
+                } else {
+                    None
                 }
-            }
-            if is_empty {
-                hset.insert(col_idx);
-            }
-        }
-        hset
+            })
+            .collect()
     }
 
     fn coordinates_of_galaxies(&self) -> Vec<Coord> {
-        let mut coords = vec![];
-
-        self.0.iter().enumerate().for_each(|(row_idx, row)| {
-            row.iter()
-                .enumerate()
-                .filter(|(_, &char)| char == '#')
-                .for_each(|(col_idx, _)| {
-                    coords.push((row_idx as isize, col_idx as isize));
-                })
-        });
-
-        coords
+        self.0
+            .positions_of(&'#')
+            .map(|(row_idx, col_idx)| (row_idx as isize, col_idx as isize))
+            .collect()
     }
 
     fn pairwise_distances(&self, scale: usize) -> Vec<usize> {
@@ -108,19 +109,17 @@ impl Grid {
     }
 }
 
-pub fn solve_part1(data: &str) -> usize {
-    let grid = Grid::new(data);
+pub fn solve_part1(grid: &Grid) -> usize {
     grid.pairwise_distances(2).into_iter().sum()
 }
 
-pub fn solve_part2(data: &str) -> usize {
-    let grid = Grid::new(data);
+pub fn solve_part2(grid: &Grid) -> usize {
     grid.pairwise_distances(1_000_000).into_iter().sum()
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{solve_part1, solve_part2};
+    use super::{solve_part1, solve_part2, Grid};
 
     #[test]
     fn expand() {
@@ -135,7 +134,8 @@ mod tests {
 .......#..
 #...#.....";
 
-        assert_eq!(solve_part1(data), 374);
-        assert_eq!(solve_part2(data), 82000210);
+        let grid = Grid::new(data).unwrap();
+        assert_eq!(solve_part1(&grid), 374);
+        assert_eq!(solve_part2(&grid), 82000210);
     }
 }
@@ -1,43 +1,42 @@
+use crate::error::{AocError, ParseError};
+use crate::solution::{Answer, Solution};
 use rayon::prelude::*;
+use std::str::FromStr;
 
-pub fn main() {
-    let data = include_str!("../../data/12.in");
+pub struct Day12;
 
-    println!("part 1: {}", solve_part1(data));
-    println!("part 2: {}", solve_part2(data));
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    type Input = Vec<Arrangement>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(data
+            .lines()
+            .map(|line| Arrangement::from_str(line).map_err(|err| err.locate(data, line)))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
 }
 
-pub fn solve_part1(data: &str) -> usize {
-    data.lines()
-        .par_bridge()
-        .map(|line| {
-            let (seq, groups) = line.split_once(' ').unwrap();
-            Arrangement {
-                seq: seq.chars().map(|c| c as u8).collect(),
-                groups: groups.split(',').map(|s| s.parse().unwrap()).collect(),
-            }
-        })
+pub fn solve_part1(arrangements: &[Arrangement]) -> usize {
+    arrangements
+        .par_iter()
         .map(|arrangement| arrangement.count_by_skipping_contiguous_blocks())
         .sum()
 }
-pub fn solve_part2(data: &str) -> usize {
-    let res = data
-        .lines()
-        .par_bridge()
-        .map(|line| {
-            let (seq, groups) = line.split_once(' ').unwrap();
-
-            let new_seq = [seq; 5].join("?");
-            let new_groups = [groups; 5].join(",");
-
-            Arrangement {
-                seq: new_seq.chars().map(|c| c as u8).collect(),
-                groups: new_groups.split(',').map(|s| s.parse().unwrap()).collect(),
-            }
-        })
+pub fn solve_part2(arrangements: &[Arrangement]) -> usize {
+    arrangements
+        .par_iter()
+        .map(|arrangement| arrangement.unfold(5))
         .map(|arrangement| arrangement.count_by_skipping_contiguous_blocks())
-        .sum();
-    res
+        .sum()
 }
 
 #[derive(Debug, Clone)]
@@ -46,7 +45,37 @@ pub struct Arrangement {
     pub groups: Vec<usize>,
 }
 
+impl FromStr for Arrangement {
+    type Err = ParseError;
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (seq, groups) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new("springs and groups like `???.### 1,1,3`", line))?;
+        if let Some(spring) = seq.chars().find(|c| !matches!(c, '.' | '#' | '?')) {
+            return Err(ParseError::new(
+                "a spring, one of `.`, `#` and `?`",
+                spring.to_string(),
+            ));
+        }
+        Ok(Arrangement {
+            seq: seq.bytes().collect(),
+            groups: groups
+                .split(',')
+                .map(|s| s.parse().map_err(|_| ParseError::new("a group size", s)))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
 impl Arrangement {
+    /// Repeat the springs (separated by a wildcard) and their groups the given number of times.
+    pub fn unfold(&self, copies: usize) -> Self {
+        Arrangement {
+            seq: vec![self.seq.clone(); copies].join(&b'?'),
+            groups: self.groups.repeat(copies),
+        }
+    }
+
     pub fn count_by_skipping_contiguous_blocks(&self) -> usize {
         let mut cache = std::collections::HashMap::new();
         Self::count_valid_arrangments(&self.seq, &self.groups, &mut cache)
@@ -75,7 +104,7 @@ impl Arrangement {
         // so whatever remains must be non-'#' to be counted
         // as a valid arrangement.
         if contiguous_blocks.is_empty() {
-            return match arrangement.iter().any(|&c| c == b'#') {
+            return match arrangement.contains(&b'#') {
                 true => 0,
                 false => 1,
             };
@@ -133,7 +162,7 @@ impl Arrangement {
 
 #[cfg(test)]
 mod tests {
-    use super::{solve_part1, solve_part2};
+    use super::{solve_part1, solve_part2, Arrangement};
 
     #[test]
     fn expand() {
@@ -144,7 +173,12 @@ mod tests {
 ????.######..#####. 1,6,5
 ?###???????? 3,2,1";
 
-        assert_eq!(solve_part1(data), 21);
-        assert_eq!(solve_part2(data), 525152);
+        let arrangements: Vec<Arrangement> = data
+            .lines()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(solve_part1(&arrangements), 21);
+        assert_eq!(solve_part2(&arrangements), 525152);
     }
 }
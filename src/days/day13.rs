@@ -1,11 +1,33 @@
+use crate::data_structures::{Grid2D, Grid2DParseError, GridView};
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
 use rayon::prelude::*;
 
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Input = Vec<Pattern>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_grids(data)?)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
 #[derive(Debug, Clone)]
-pub struct Grid2D(Vec<Vec<u8>>);
+pub struct Pattern(Grid2D<u8>);
 
-impl std::fmt::Display for Grid2D {
+impl std::fmt::Display for Pattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in self.0.iter().map(|row| {
+        for row in self.0.iter_rows().map(|row| {
             row.iter()
                 .map(|&c| (c as char).to_string())
                 .collect::<String>()
@@ -40,49 +62,30 @@ impl Reflection {
     }
 }
 
-impl Grid2D {
-    fn is_reflection_line_horizontal(&self, index: isize) -> bool {
+/// The rows of the pattern that have a line of reflection right above them.
+/// Columns are rows of the transposed pattern, so this finds the vertical lines too.
+fn reflection_lines(pattern: GridView<'_, u8>) -> impl Iterator<Item = usize> + '_ {
+    (1..pattern.rows()).filter(move |&index| {
         let upwards = (0..index).rev();
-        let downwards = index..(self.0.len() as isize);
+        let downwards = index..pattern.rows();
         upwards
             .zip(downwards)
-            .all(|(up, down)| self.0[up as usize] == self.0[down as usize])
-    }
-
-    fn is_reflection_line_vertical(&self, index: isize) -> bool {
-        let leftwards = (0..index).rev();
-        let rightwards = index..(self.0.get(0).unwrap().len() as isize);
-        leftwards
-            .zip(rightwards)
-            .all(|(left, right)| self.get_col(left as usize) == self.get_col(right as usize))
-    }
+            .all(|(up, down)| pattern.row(up).eq(pattern.row(down)))
+    })
+}
 
+impl Pattern {
     pub fn find_horizontal_reflection(&self) -> impl Iterator<Item = Reflection> + '_ {
-        (1..(self.0.len() as isize))
-            .filter(|&index| self.is_reflection_line_horizontal(index))
-            .map(|v| Reflection::Horizontal(v as usize))
-    }
-
-    fn get_col(&self, index: usize) -> Vec<u8> {
-        (0..self.0.len())
-            .map(|row_index| self.0[row_index][index])
-            .collect()
+        reflection_lines(self.0.view()).map(Reflection::Horizontal)
     }
 
     pub fn find_vertical_reflection(&self) -> impl Iterator<Item = Reflection> + '_ {
-        (1..(self.0.get(0).unwrap().len() as isize))
-            .filter(|&index| self.is_reflection_line_vertical(index))
-            .map(|v| Reflection::Vertical(v as usize))
+        reflection_lines(self.0.transpose()).map(Reflection::Vertical)
     }
 
     fn correct_smudge(&self, row_index: usize, col_index: usize) -> Self {
-        let mut res = Self(self.0.clone());
-        let value = res
-            .0
-            .get_mut(row_index)
-            .unwrap()
-            .get_mut(col_index)
-            .unwrap();
+        let mut res = self.clone();
+        let value = &mut res.0[(row_index, col_index)];
         if *value == b'.' {
             *value = b'#';
         } else {
@@ -101,8 +104,8 @@ impl Grid2D {
         let old_reflections: std::collections::HashSet<_> =
             self.find_lines_of_reflection().collect();
 
-        let rows = self.0.len();
-        let cols = self.0[0].len();
+        let rows = self.0.rows;
+        let cols = self.0.columns;
 
         for row_index in 0..rows {
             for col_index in 0..cols {
@@ -117,47 +120,30 @@ impl Grid2D {
                 }
             }
         }
-        panic!("No smudges found!: {}", self);
+        None
     }
 }
 
-pub fn main() {
-    let data = include_str!("../../data/13.in");
-
-    println!("part 1: {}", solve_part1(data));
-    println!("part 2: {}", solve_part2(data));
+pub fn parse_grids(data: &str) -> Result<Vec<Pattern>, Grid2DParseError> {
+    data.split("\n\n")
+        .map(|block| Ok(Pattern(block.parse()?)))
+        .collect()
 }
 
-pub fn solve_part1(data: &str) -> usize {
-    data.split("\n\n")
-        .par_bridge()
-        .map(|block| {
-            Grid2D(
-                block
-                    .lines()
-                    .map(|row| row.chars().map(|c| (c as u8)).collect())
-                    .collect(),
-            )
-        })
+pub fn solve_part1(grids: &[Pattern]) -> usize {
+    grids
+        .par_iter()
         .map(|grid| grid.find_lines_of_reflection().next())
         .map(|reflection| reflection.map(|r| r.score()).unwrap_or_default())
         .sum()
 }
 
-pub fn solve_part2(data: &str) -> usize {
-    data.split("\n\n")
-        .par_bridge()
-        .map(|block| {
-            let grid = Grid2D(
-                block
-                    .lines()
-                    .map(|row| row.chars().map(|c| (c as u8)).collect())
-                    .collect(),
-            );
-            grid
-        })
-        .map(|grid| grid.find_new_line_of_reflection().unwrap())
-        .map(|reflection| reflection.score())
+/// `None` if there's a pattern without a smudge that makes a new line of reflection.
+pub fn solve_part2(grids: &[Pattern]) -> Option<usize> {
+    grids
+        .par_iter()
+        .map(|grid| grid.find_new_line_of_reflection())
+        .map(|reflection| reflection.map(|r| r.score()))
         .sum()
 }
 
@@ -165,7 +151,7 @@ pub fn solve_part2(data: &str) -> usize {
 mod tests {
     use std::collections::HashSet;
 
-    use super::{solve_part1, solve_part2};
+    use super::{parse_grids, solve_part1, solve_part2};
 
     #[test]
     fn expand() {
@@ -185,8 +171,9 @@ mod tests {
 ..##..###
 #....#..#";
 
-        assert_eq!(solve_part1(data), 405);
-        assert_eq!(solve_part2(data), 400);
+        let grids = parse_grids(data).unwrap();
+        assert_eq!(solve_part1(&grids), 405);
+        assert_eq!(solve_part2(&grids), Some(400));
     }
 
     #[test]
@@ -208,14 +195,10 @@ mod tests {
 ##..##.
 ...#..#";
 
-        let grid = super::Grid2D(
-            data.lines()
-                .map(|row| row.chars().map(|c| (c as u8)).collect())
-                .collect(),
-        );
+        let grid = super::Pattern(data.parse().unwrap());
 
         assert_eq!(
-            Some(crate::Reflection::Horizontal(8)),
+            Some(super::Reflection::Horizontal(8)),
             grid.find_new_line_of_reflection()
         );
     }
@@ -238,16 +221,12 @@ mod tests {
 ##..##.
 ...#..#";
 
-        let grid = super::Grid2D(
-            data.lines()
-                .map(|row| row.chars().map(|c| (c as u8)).collect())
-                .collect(),
-        );
+        let grid = super::Pattern(data.parse().unwrap());
 
         assert_eq!(
             vec![
-                crate::Reflection::Horizontal(8),
-                crate::Reflection::Horizontal(1)
+                super::Reflection::Horizontal(8),
+                super::Reflection::Horizontal(1)
             ]
             .into_iter()
             .collect::<HashSet<_>>(),
@@ -0,0 +1,300 @@
+use crate::data_structures::{Direction, Grid2D, Grid2DParseError, GridViewMut};
+use crate::error::{AocError, ParseError};
+use crate::math::detect_cycle;
+use crate::solution::{Answer, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    type Input = Platform;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(Platform::parse_str(data)?)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+}
+
+pub fn solve_part1(grid: &Platform) -> usize {
+    let mut grid = grid.clone();
+    tilt_north(grid.0.view_mut());
+    grid.weight()
+}
+
+pub fn solve_part2(grid: &Platform) -> usize {
+    let mut grid = grid.clone();
+    let steps = 1_000_000_000;
+
+    // Without a cycle there's nothing to skip, and every step has to be run.
+    let (steps_before_reaching_cycle, cycle_length) =
+        detect_cycle(&grid, |grid| grid.cycle(), Some(steps)).unwrap_or((steps, 1));
+
+    let steps_in_the_last_cycle = (steps - steps_before_reaching_cycle) % cycle_length;
+
+    for _ in 0..(steps_before_reaching_cycle + steps_in_the_last_cycle) {
+        grid = grid.cycle();
+    }
+    grid.weight()
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Platform(Grid2D<Rock>);
+
+pub type Coordinate = (usize, usize);
+
+impl std::fmt::Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Platform {
+    pub fn parse_str(data: &str) -> Result<Self, Grid2DParseError> {
+        Ok(Platform(data.parse()?))
+    }
+
+    pub fn get_number_of_columns(&self) -> usize {
+        self.0.columns
+    }
+
+    pub fn state(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn weight(&self) -> usize {
+        self.0
+            .iter_rows()
+            .rev()
+            .enumerate()
+            .map(|(row_index, row)| {
+                row.iter().filter(|&value| *value == Rock::Rounded).count() * (row_index + 1)
+            })
+            .sum()
+    }
+
+    /// Tilting is written once, for the north; for the other directions the platform is
+    /// turned so that they face north while tilting. It can't be tilted diagonally,
+    /// so those are `None`.
+    pub fn shift(&self, direction: Direction) -> Option<Self> {
+        let mut platform = self.clone();
+        let view = platform.0.view_mut();
+        tilt_north(match direction {
+            Direction::Up => view,
+            Direction::Down => view.flip_v(),
+            Direction::Left => view.transpose(),
+            Direction::Right => view.rotate_ccw(),
+            _ => return None,
+        });
+        Some(platform)
+    }
+
+    pub fn get_positions_of_cube_rocks(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.0.positions_of(&Rock::Cube)
+    }
+
+    /// North, west, south, then east, all on the same copy of the platform.
+    pub fn cycle(&self) -> Self {
+        let mut platform = self.clone();
+        tilt_north(platform.0.view_mut());
+        tilt_north(platform.0.view_mut().transpose());
+        tilt_north(platform.0.view_mut().flip_v());
+        tilt_north(platform.0.view_mut().rotate_ccw());
+        platform
+    }
+}
+
+/// Roll every rounded rock up its column until it hits the edge, a cube rock or another rounded rock.
+fn tilt_north(mut platform: GridViewMut<'_, Rock>) {
+    for column in 0..platform.columns() {
+        let mut next_rock_spot = 0;
+        for row in 0..platform.rows() {
+            match platform[(row, column)] {
+                Rock::Space => {}
+                Rock::Cube => next_rock_spot = row + 1,
+                Rock::Rounded => {
+                    platform[(row, column)] = Rock::Space;
+                    platform[(next_rock_spot, column)] = Rock::Rounded;
+                    next_rock_spot += 1;
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rock {
+    Rounded,
+    Cube,
+    Space,
+}
+
+impl TryFrom<char> for Rock {
+    type Error = ParseError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'O' => Ok(Self::Rounded),
+            '#' => Ok(Self::Cube),
+            '.' => Ok(Self::Space),
+            _ => Err(ParseError::new("one of `O#.`", value.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for Rock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Rounded => write!(f, "O"),
+            Self::Cube => write!(f, "#"),
+            Self::Space => write!(f, "."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{solve_part1, solve_part2, Direction, Platform};
+
+    #[test]
+    fn shift() {
+        let input = r"O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+        let grid = Platform::parse_str(input).unwrap();
+
+        let south = r".....#....
+....#....#
+...O.##...
+...#......
+O.O....O#O
+O.#..O.#.#
+O....#....
+OO....OO..
+#OO..###..
+#OO.O#...O";
+
+        let west = r"O....#....
+OOO.#....#
+.....##...
+OO.#OO....
+OO......#.
+O.#O...#.#
+O....#OO..
+O.........
+#....###..
+#OO..#....";
+
+        let east = r"....O#....
+.OOO#....#
+.....##...
+.OO#....OO
+......OO#.
+.O#...O#.#
+....O#..OO
+.........O
+#....###..
+#..OO#....";
+
+        let north = r"OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....";
+
+        assert_eq!(&grid.shift(Direction::Up).unwrap().to_string(), north);
+        assert_eq!(&grid.shift(Direction::Right).unwrap().to_string(), east);
+        assert_eq!(&grid.shift(Direction::Left).unwrap().to_string(), west);
+        assert_eq!(&grid.shift(Direction::Down).unwrap().to_string(), south);
+        assert!(grid.shift(Direction::TopLeft).is_none());
+    }
+
+    #[test]
+    fn smol() {
+        let data = r"O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+        let grid = Platform::parse_str(data).unwrap();
+        assert_eq!(solve_part1(&grid), 136);
+        assert_eq!(solve_part2(&grid), 64);
+    }
+
+    #[test]
+    fn cycle() {
+        let input = r"O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+        let expected_values = vec![
+            r".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....",
+            r".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#..OO###..
+#.OOO#...O",
+            r".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O",
+        ];
+
+        let mut grid = Platform::parse_str(input).unwrap();
+        for expected in expected_values.into_iter() {
+            assert_eq!(&grid.cycle().to_string(), expected);
+            grid = grid.cycle();
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::error::{AocError, ParseError};
+use crate::solution::{Answer, Solution};
+use std::str::FromStr;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Input = Vec<Step>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_steps(data)?)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+}
+
+/// A step of the initialization sequence, e.g. `rn=1` or `cm-`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// The step as written, which is what part 1 hashes.
+    pub text: String,
+    pub label: String,
+    /// The focal length of the lens to put in the box, or `None` to take it out.
+    pub focal_length: Option<usize>,
+}
+
+impl FromStr for Step {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || ParseError::new("a step like `rn=1` or `cm-`", s);
+        let (label, focal_length) = match s.split_once('=') {
+            Some((label, focal_length)) => {
+                (label, Some(focal_length.parse().map_err(|_| malformed())?))
+            }
+            None => (s.strip_suffix('-').ok_or_else(malformed)?, None),
+        };
+        if label.is_empty() || !label.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(malformed());
+        }
+
+        Ok(Step {
+            text: s.to_string(),
+            label: label.to_string(),
+            focal_length,
+        })
+    }
+}
+
+pub fn parse_steps(data: &str) -> Result<Vec<Step>, ParseError> {
+    data.lines()
+        .flat_map(|line| {
+            line.split(',')
+                .map(move |step| Step::from_str(step).map_err(|err| err.locate(data, line)))
+        })
+        .collect()
+}
+
+pub fn part1_hash(s: &str) -> usize {
+    let mut current = 0;
+    for c in s.chars().filter(|c| *c != '\n') {
+        let code = (c as u8) as usize;
+        current = ((current + code) * 17) % 256;
+    }
+    current
+}
+
+pub fn solve_part2(steps: &[Step]) -> usize {
+    const VAL: Vec<(String, usize)> = vec![];
+    let mut boxes: [Vec<(String, usize)>; 256] = [VAL; 256];
+
+    steps.iter().for_each(|step| {
+        let lens = step.label.as_str();
+
+        let box_index = part1_hash(lens);
+
+        let valid_box = &mut boxes[box_index];
+        if let Some(power) = step.focal_length {
+            if let Some(found_index) = valid_box
+                .iter()
+                .position(|(lens_in_box, _)| lens_in_box == lens)
+            {
+                valid_box[found_index] = (lens.to_string(), power);
+            } else {
+                valid_box.push((lens.to_string(), power));
+            }
+        } else {
+            valid_box.retain(|(lens_in_box, _)| lens_in_box != lens);
+        }
+    });
+
+    (0..256usize)
+        .map(|box_idx| {
+            boxes[box_idx]
+                .iter()
+                .enumerate()
+                .map(|(slot, (_, power))| (slot + 1) * *power * (box_idx + 1))
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+#[test]
+fn test_sample() {
+    let data = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+    let steps = parse_steps(data).unwrap();
+    assert_eq!(solve_part1(&steps), 1320);
+    assert_eq!(solve_part2(&steps), 145);
+}
+
+pub fn solve_part1(steps: &[Step]) -> usize {
+    steps.iter().map(|step| part1_hash(&step.text)).sum()
+}
@@ -0,0 +1,163 @@
+use crate::data_structures::Direction;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    type Input = Mirrors;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(Mirrors::new(data))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+}
+
+pub fn solve_part1(mirrors: &Mirrors) -> usize {
+    mirrors.trace_rays((0, 0), Direction::Right).len()
+}
+
+pub fn solve_part2(mirrors: &Mirrors) -> usize {
+    let mut sources_and_directions = vec![];
+
+    // Check rays going down from the top edge.
+    sources_and_directions
+        .extend((0..mirrors.columns).map(|col_idx| ((0, col_idx as isize), Direction::Down)));
+    // Check rays going up from the bottom edge.
+    sources_and_directions.extend(
+        (0..mirrors.columns)
+            .map(|col_idx| ((mirrors.rows as isize - 1, col_idx as isize), Direction::Up)),
+    );
+    // Check rays going right from the left edge.
+    sources_and_directions
+        .extend((0..mirrors.rows).map(|row_idx| ((row_idx as isize, 0), Direction::Right)));
+    // Check rays going right from the right edge.
+    sources_and_directions.extend((0..mirrors.rows).map(|row_idx| {
+        (
+            (row_idx as isize, mirrors.columns as isize - 1),
+            Direction::Left,
+        )
+    }));
+
+    sources_and_directions
+        .par_iter()
+        .map(|(source, direction)| mirrors.trace_rays(*source, *direction).len())
+        .max()
+        .unwrap()
+}
+
+pub type Point = (isize, isize);
+
+pub struct Mirrors {
+    inner: HashMap<Point, u8>,
+    rows: usize,
+    columns: usize,
+}
+
+impl Mirrors {
+    #[inline(always)]
+    pub fn is_mirror(c: u8) -> bool {
+        c == b'-' || c == b'|' || c == b'/' || c == b'\\'
+    }
+    pub fn new(data: &str) -> Self {
+        let inner = HashMap::from_iter(data.lines().enumerate().flat_map(|(row_idx, row)| {
+            row.chars()
+                .enumerate()
+                .filter(|(_, value)| Self::is_mirror(*value as u8))
+                .map(move |(col_idx, value)| ((row_idx as isize, col_idx as isize), value as u8))
+        }));
+
+        let grid = data.lines().collect::<Vec<_>>();
+
+        Self {
+            inner,
+            rows: grid.len(),
+            columns: grid[0].len(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn next_coordinate(&self, source: Point, direction: Direction) -> Option<Point> {
+        let (delta_row, delta_col): (isize, isize) = direction.into();
+        let (row, col) = (source.0 + delta_row, source.1 + delta_col);
+        let on_grid =
+            (0..self.rows as isize).contains(&row) && (0..self.columns as isize).contains(&col);
+        on_grid.then_some((row, col))
+    }
+
+    pub fn step(
+        &self,
+        outgoing: Direction,
+        coordinate: Point,
+    ) -> impl Iterator<Item = (Point, Direction)> + '_ {
+        let char = *self
+            .inner
+            .get(&(coordinate.0, coordinate.1))
+            .unwrap_or(&b'.');
+
+        let directions = match (char, outgoing) {
+            (b'/' | b'\\', _) => vec![outgoing.reflect(char as char).unwrap()],
+            // Split north and south.
+            (b'|', Direction::Left | Direction::Right) => vec![Direction::Up, Direction::Down],
+            // Split east and west.
+            (b'-', Direction::Up | Direction::Down) => vec![Direction::Right, Direction::Left],
+            // keep going same direction
+            _ => vec![outgoing],
+        };
+
+        directions.into_iter().filter_map(move |direction| {
+            self.next_coordinate(coordinate, direction)
+                .map(|neighbor| (neighbor, direction))
+        })
+    }
+
+    pub fn trace_rays(&self, source: Point, direction: Direction) -> HashSet<Point> {
+        let mut seen = HashSet::new();
+        let mut tiles_seen = HashSet::new();
+        let mut queue = vec![(source, direction)]
+            .into_iter()
+            .collect::<VecDeque<_>>();
+
+        while let Some((source, direction)) = queue.pop_front() {
+            tiles_seen.insert(source); // track the distinct tiles that were covered.
+            seen.insert((source, direction)); // track which direction the ray was facing when it got here.
+            queue.extend(
+                self.step(direction, source)
+                    .filter(|(src, dir)| !seen.contains(&(*src, *dir))),
+            )
+        }
+        tiles_seen
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solve_part1, solve_part2, Mirrors};
+
+    #[test]
+    fn smol() {
+        let data = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+        let mirrors = Mirrors::new(data);
+        assert_eq!(solve_part1(&mirrors), 46);
+        assert_eq!(solve_part2(&mirrors), 51);
+    }
+}
@@ -1,17 +1,30 @@
-use aoc_2023::data_structures::{Direction, NeighborhoodShape, SparseGrid2D};
+use crate::data_structures::{Direction, NeighborhoodShape, ParseMode, SparseGrid2D};
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
 use rayon::prelude::*;
 
 use pathfinding::prelude::astar;
 
-pub fn main() {
-    let data = include_str!("../../data/17.in");
-    println!("part 1: {}", solve_part1(data));
-    println!("part 2: {}", solve_part2(data));
-}
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    type Input = SparseGrid2D<HeatLoss>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(SparseGrid2D::parse(data, ParseMode::Strict)?)
+    }
 
-pub fn solve_part1(data: &str) -> isize {
-    let grid = data.parse::<SparseGrid2D<HeatLoss>>().unwrap();
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
 
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+}
+
+pub fn solve_part1(grid: &SparseGrid2D<HeatLoss>) -> isize {
     let start_node = NodeKey::new((0, 0), None, 0);
     let end_node = NodeKey::new(
         (grid.rows as isize - 1, grid.columns as isize - 1),
@@ -42,9 +55,7 @@ pub fn solve_part1(data: &str) -> isize {
         .unwrap()
 }
 
-pub fn solve_part2(data: &str) -> isize {
-    let grid = data.parse::<SparseGrid2D<HeatLoss>>().unwrap();
-
+pub fn solve_part2(grid: &SparseGrid2D<HeatLoss>) -> isize {
     let start_node = NodeKey::new((0, 0), None, 0);
     let end_node = NodeKey::new(
         (grid.rows as isize - 1, grid.columns as isize - 1),
@@ -111,17 +122,17 @@ impl MaxConsecutiveRunNeighbors for SparseGrid2D<HeatLoss> {
         } = key;
 
         let Some(direction) = direction else {
-            return
-                self
-                .neighbors((coord.0 as usize, coord.1 as usize), NeighborhoodShape::Plus)
-                .map(|(coord, direction, _)| {
-                    NodeKey {
-                        coord: (coord.0 as isize, coord.1 as isize),
-                        direction: Some(direction),
-                        run_length: 1
-                    }
+            return self
+                .neighbors(
+                    (coord.0 as usize, coord.1 as usize),
+                    NeighborhoodShape::Plus,
+                )
+                .map(|(coord, direction, _)| NodeKey {
+                    coord: (coord.0 as isize, coord.1 as isize),
+                    direction: Some(direction),
+                    run_length: 1,
                 })
-                .collect()
+                .collect();
         };
 
         let mut result = vec![];
@@ -164,17 +175,17 @@ impl MaxConsecutiveRunNeighbors for SparseGrid2D<HeatLoss> {
         } = key;
 
         let Some(direction) = direction else {
-            return
-                self
-                .neighbors((coord.0 as usize, coord.1 as usize), NeighborhoodShape::Plus)
-                .map(|(coord, direction, _)| {
-                    NodeKey {
-                        coord: (coord.0 as isize, coord.1 as isize),
-                        direction: Some(direction),
-                        run_length: 1
-                    }
+            return self
+                .neighbors(
+                    (coord.0 as usize, coord.1 as usize),
+                    NeighborhoodShape::Plus,
+                )
+                .map(|(coord, direction, _)| NodeKey {
+                    coord: (coord.0 as isize, coord.1 as isize),
+                    direction: Some(direction),
+                    run_length: 1,
                 })
-                .collect()
+                .collect();
         };
 
         let mut result = vec![];
@@ -251,8 +262,8 @@ mod tests {
 999999999991
 999999999991
 999999999991";
-        assert_eq!(71, solve_part2(data));
-        assert_eq!(59, solve_part1(data));
+        assert_eq!(71, solve_part2(&Day17::parse(data).unwrap()));
+        assert_eq!(59, solve_part1(&Day17::parse(data).unwrap()));
     }
 
     #[test]
@@ -270,7 +281,7 @@ mod tests {
 1224686865563
 2546548887735
 4322674655533";
-        assert_eq!(102, solve_part1(data));
-        assert_eq!(94, solve_part2(data));
+        assert_eq!(102, solve_part1(&Day17::parse(data).unwrap()));
+        assert_eq!(94, solve_part2(&Day17::parse(data).unwrap()));
     }
 }
@@ -0,0 +1,148 @@
+use crate::data_structures::{Direction, LateralPolygon};
+use crate::error::{AocError, ParseError};
+use crate::solution::{Answer, Solution};
+use regex::Regex;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    type Input = DigPlan;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_dig_plan(data)?)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve(&input.part1).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve(&input.part2).into()
+    }
+}
+
+/// TIL: [Pick's Theorem] states that `A = i + b/2 - 1`
+/// where `i` denotes the number of lattice points interior to the polygon,
+/// `b` denotes the number of lattice points on the boundary of the polygon,
+/// and `A` denotes the area of the polygon.
+///
+/// The Shoelace formula gives us the area of the polygon, `A`.
+/// The perimeter `b` is simply the number of distinct lattice points on the horizontal/vertical edges of the polygon.
+///
+/// For this problem we want to calculate `i + b`, so using Pick's theorem, we have
+/// the desired answer is `A + b / 2 + 1`.
+///
+/// [Pick's Theorem]: https://en.wikipedia.org/wiki/Pick%27s_theorem
+pub fn solve(steps: &[(Direction, isize)]) -> usize {
+    build_polygon(steps).lattice_points()
+}
+
+/// The dig plan read two ways: as written, and decoded from the hex "colors".
+#[derive(Debug)]
+pub struct DigPlan {
+    pub part1: Vec<(Direction, isize)>,
+    pub part2: Vec<(Direction, isize)>,
+}
+
+pub fn parse_dig_plan(data: &str) -> Result<DigPlan, ParseError> {
+    let dig_plan_re = Regex::new(r"^([DLRU]) (\d+) \((.*)\)$").unwrap();
+
+    let mut plan = DigPlan {
+        part1: vec![],
+        part2: vec![],
+    };
+
+    for line in data.lines() {
+        let captures = dig_plan_re.captures(line).ok_or_else(|| {
+            ParseError::new("a step like `R 6 (#70c710)`", line).locate(data, line)
+        })?;
+
+        let dir = Direction::try_from(captures[1].chars().next().unwrap_or_default())
+            .map_err(|err| err.locate(data, line))?;
+        let steps: isize = captures[2]
+            .parse()
+            .map_err(|_| ParseError::new("a number of steps", &captures[2]).locate(data, line))?;
+        plan.part1.push((dir, steps));
+
+        // Five hex digits for the distance, then one for the direction.
+        let color = &captures[3];
+        let malformed = || ParseError::new("a color like `#70c710`", color).locate(data, line);
+        let hex = color
+            .strip_prefix('#')
+            .filter(|hex| hex.len() == 6)
+            .ok_or_else(malformed)?;
+        let hex_encoded_dist = usize::from_str_radix(&hex[..5], 16).map_err(|_| malformed())?;
+        let dir = match &hex[5..] {
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "3" => Direction::Up,
+            _ => return Err(malformed()),
+        };
+        plan.part2.push((dir, hex_encoded_dist as isize));
+    }
+
+    Ok(plan)
+}
+
+pub fn build_polygon(steps: &[(Direction, isize)]) -> LateralPolygon {
+    let mut current_coord: (isize, isize) = (0, 0);
+    let mut res = vec![current_coord];
+
+    for (dir, steps) in steps {
+        let (delta_row, delta_column): (isize, isize) = (*dir).into();
+        current_coord = (
+            current_coord.0 + delta_row * steps,
+            current_coord.1 + delta_column * steps,
+        );
+        res.push(current_coord);
+    }
+
+    LateralPolygon::new(res.into_iter())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smol() {
+        let data = r"R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+        let plan = parse_dig_plan(data).unwrap();
+        assert_eq!(solve(&plan.part1), 62);
+        assert_eq!(solve(&plan.part2), 952408144115);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse_dig_plan("R 6 (#70c710)\nD 5 (#0dc579)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a color like `#70c710`, found \"#0dc579\" at line 2, column 6
+  |
+2 | D 5 (#0dc579)
+  |      ^^^^^^^"
+        );
+        assert_eq!(
+            parse_dig_plan("R 6 (#70c710)\nX 5 (#0dc571)")
+                .unwrap_err()
+                .location
+                .map(|location| (location.line, location.column)),
+            Some((2, 1))
+        );
+    }
+}
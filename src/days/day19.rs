@@ -5,6 +5,28 @@ use lazy_static::lazy_static;
 use rayon::prelude::*;
 use regex::Regex;
 
+use crate::error::{AocError, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    type Input = Game;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        data.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+}
+
 lazy_static! {
     static ref RULE_CONDITIONAL_RE: Regex =
         Regex::new(r"(?<part>[xmas])(?<conditional>[<>])(?<threshold>\d+):(?<target>\w+)").unwrap();
@@ -20,19 +42,26 @@ pub struct Game {
 }
 
 impl FromStr for Game {
-    type Err = String;
+    type Err = AocError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (workflows, ratings) = s.split_once("\n\n").unwrap();
+        let (workflows, ratings) = s.split_once("\n\n").ok_or_else(|| {
+            let first_line = s.lines().next().unwrap_or_default();
+            ParseError::new(
+                "the workflows and the ratings separated by a blank line",
+                first_line,
+            )
+            .locate(s, first_line)
+        })?;
 
         let workflows = workflows
             .lines()
-            .map(Workflow::from_str)
-            .collect::<Result<Vec<_>, String>>()?;
+            .map(|line| Workflow::from_str(line).map_err(|err| err.locate(s, line)))
+            .collect::<Result<Vec<_>, _>>()?;
 
         let ratings = ratings
             .lines()
-            .map(Ratings::from_str)
-            .collect::<Result<Vec<_>, String>>()?;
+            .map(|line| Ratings::from_str(line).map_err(|err| err.locate(s, line)))
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut workflow_pool = std::collections::HashMap::new();
 
@@ -40,6 +69,28 @@ impl FromStr for Game {
             workflow_pool.insert(workflow.id.clone(), workflow.clone());
         }
 
+        // Every part goes through the workflows starting at `in`, so make sure they can't dead end.
+        if !workflow_pool.contains_key("in") {
+            return Err(AocError::InvalidInput(
+                "there's no `in` workflow to start from".to_string(),
+            ));
+        }
+        for workflow in workflows.iter() {
+            for rule in &workflow.rules {
+                if let Rule::Jump { target }
+                | Rule::JumpIfLessThan { target, .. }
+                | Rule::JumpIfGreaterThan { target, .. } = rule
+                {
+                    if !workflow_pool.contains_key(target) {
+                        return Err(AocError::InvalidInput(format!(
+                            "workflow `{}` jumps to `{target}`, which doesn't exist",
+                            workflow.id
+                        )));
+                    }
+                }
+            }
+        }
+
         Ok(Self {
             ratings,
             workflows: workflow_pool,
@@ -47,8 +98,7 @@ impl FromStr for Game {
     }
 }
 
-pub fn solve_part1(data: &str) -> usize {
-    let game = data.parse::<Game>().unwrap();
+pub fn solve_part1(game: &Game) -> usize {
     let mut accepted_ratings = std::collections::HashSet::new();
 
     for (rating_idx, rating) in game.ratings.iter().enumerate() {
@@ -71,9 +121,7 @@ pub fn solve_part1(data: &str) -> usize {
         .sum()
 }
 
-pub fn solve_part2(data: &str) -> usize {
-    let game = data.parse::<Game>().unwrap();
-
+pub fn solve_part2(game: &Game) -> usize {
     Ratings::distinct_combinations()
         .par_bridge()
         .progress_count(4_000 * 4_000 * 4_000 * 4_000)
@@ -82,24 +130,18 @@ pub fn solve_part2(data: &str) -> usize {
             loop {
                 let (accepted, maybe_next_workflow) = workflow.process(&rating);
                 let Some(next_workflow_id) = maybe_next_workflow else {
-                if accepted {
-                    return Some(true)
-                } else {
-                    return None;
-                }
-            };
+                    if accepted {
+                        return Some(true);
+                    } else {
+                        return None;
+                    }
+                };
                 workflow = game.workflows.get(&next_workflow_id).unwrap().clone();
             }
         })
         .count()
 }
 
-pub fn main() {
-    let data = include_str!("../../data/19.in");
-    println!("part 1: {}", solve_part1(data));
-    println!("part 2: {}", solve_part2(data));
-}
-
 #[derive(Debug, Clone)]
 pub struct Ratings {
     inner: HashMap<Part, usize>,
@@ -131,22 +173,28 @@ impl AsRef<HashMap<Part, usize>> for Ratings {
 }
 
 impl FromStr for Ratings {
-    type Err = String;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let captures = RATING_RE.captures(s).expect("malformed ratings?");
-        let inner = captures
-            .name("ratings")
-            .unwrap()
-            .as_str()
+        let malformed = || ParseError::new("ratings like `{x=787,m=2655,a=1222,s=2876}`", s);
+        let captures = RATING_RE.captures(s).ok_or_else(malformed)?;
+        let inner = captures["ratings"]
             .split(',')
             .map(|rating_str| {
-                let (part_str, value) = rating_str.split_once('=').unwrap();
-                let value = value.parse::<usize>().unwrap();
-                let part: Part = part_str.chars().next().unwrap().into();
-                (part, value)
+                let malformed = || ParseError::new("a rating like `x=787`", rating_str);
+                let (part_str, value) = rating_str.split_once('=').ok_or_else(malformed)?;
+                let value = value.parse::<usize>().map_err(|_| malformed())?;
+                let mut chars = part_str.chars();
+                let (Some(part), None) = (chars.next(), chars.next()) else {
+                    return Err(malformed());
+                };
+                Ok((Part::try_from(part)?, value))
             })
-            .collect::<HashMap<Part, usize>>();
+            .collect::<Result<HashMap<Part, usize>, _>>()?;
 
+        // Every rule may look at any of the categories.
+        if inner.len() != 4 {
+            return Err(malformed());
+        }
         Ok(Self { inner })
     }
 }
@@ -158,17 +206,17 @@ pub struct Workflow {
 }
 
 impl std::str::FromStr for Workflow {
-    type Err = String;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let captures = WORKFLOW_RE.captures(s).expect("malformed workflow?");
-
-        let id = captures.name("id").unwrap().as_str().to_string();
-        let rules = captures.name("rules").unwrap().as_str();
+        let captures = WORKFLOW_RE
+            .captures(s)
+            .ok_or_else(|| ParseError::new("a workflow like `px{a<2006:qkq,m>2090:A,rfg}`", s))?;
 
-        let rules = rules
+        let id = captures["id"].to_string();
+        let rules = captures["rules"]
             .split(',')
             .map(Rule::from_str)
-            .collect::<Result<Vec<_>, String>>()?;
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(Self { id, rules })
     }
@@ -267,53 +315,45 @@ pub enum Rule {
     },
 }
 
-impl From<char> for Part {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Part {
+    type Error = ParseError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            'x' => Self::X,
-            'm' => Self::M,
-            'a' => Self::A,
-            's' => Self::S,
-            _ => panic!("unknown part?!"),
+            'x' => Ok(Self::X),
+            'm' => Ok(Self::M),
+            'a' => Ok(Self::A),
+            's' => Ok(Self::S),
+            _ => Err(ParseError::new("one of `xmas`", value.to_string())),
         }
     }
 }
 
 impl std::str::FromStr for Rule {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || ParseError::new("a rule like `a<2006:qkq`", s);
         match RULE_CONDITIONAL_RE.captures(s) {
-            None => Ok(match s {
-                "A" => Rule::Conclude { accept: true },
-                "R" => Rule::Conclude { accept: false },
-                _ => Rule::Jump {
-                    target: s.to_string(),
-                },
-            }),
+            None => match s {
+                "A" => Ok(Rule::Conclude { accept: true }),
+                "R" => Ok(Rule::Conclude { accept: false }),
+                _ if !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_') => {
+                    Ok(Rule::Jump {
+                        target: s.to_string(),
+                    })
+                }
+                _ => Err(malformed()),
+            },
             Some(capture) => {
-                let part: Part = capture
-                    .name("part")
-                    .unwrap()
-                    .as_str()
-                    .chars()
-                    .next()
-                    .unwrap()
-                    .into();
-                let conditional = capture
-                    .name("conditional")
-                    .unwrap()
-                    .as_str()
+                let part = Part::try_from(capture["part"].chars().next().ok_or_else(malformed)?)?;
+                let conditional = capture["conditional"]
                     .chars()
                     .next()
-                    .unwrap();
-                let threshold = capture
-                    .name("threshold")
-                    .unwrap()
-                    .as_str()
+                    .ok_or_else(malformed)?;
+                let threshold = capture["threshold"]
                     .parse::<usize>()
-                    .unwrap();
-                let target = capture.name("target").unwrap().as_str();
+                    .map_err(|_| malformed())?;
+                let target = &capture["target"];
 
                 match (conditional, target) {
                     ('<', "A" | "R") => Ok(Rule::ConcludeIfLessThan {
@@ -336,7 +376,7 @@ impl std::str::FromStr for Rule {
                         target: target.to_string(),
                         value: threshold,
                     }),
-                    _ => Err(format!("Unknown case: {}", s)),
+                    _ => Err(malformed()),
                 }
             }
         }
@@ -367,14 +407,15 @@ hdj{m>838:A,pv}
 {x=2461,m=1339,a=466,s=291}
 {x=2127,m=1623,a=2188,s=1013}";
 
-        assert_eq!(solve_part1(data), 19114);
-        assert_eq!(solve_part2(data), 167409079868000);
+        let game = data.parse::<Game>().unwrap();
+        assert_eq!(solve_part1(&game), 19114);
+        assert_eq!(solve_part2(&game), 167409079868000);
     }
     #[test]
     fn parse_rule() {
-        let rule_strs = vec!["a<2006:qkq", "m>2090:A", "rfg", "a>3333:R", "R", "A"];
+        let rule_strs = ["a<2006:qkq", "m>2090:A", "rfg", "a>3333:R", "R", "A"];
 
-        let expected = vec![
+        let expected = [
             Rule::JumpIfLessThan {
                 part: Part::A,
                 value: 2006,
@@ -402,4 +443,33 @@ hdj{m>838:A,pv}
             assert_eq!(rule, expected[idx]);
         }
     }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            "a<2006qkq".parse::<Rule>(),
+            Err(ParseError::new("a rule like `a<2006:qkq`", "a<2006qkq"))
+        );
+        assert!("{x=787,m=2655,a=1222}".parse::<Ratings>().is_err());
+
+        let err = "in{x<10:A,R}\npx{a<2006qkq,m>2090:A,rfg}\n\n{x=1,m=2,a=3,s=4}"
+            .parse::<Game>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "malformed input: expected a rule like `a<2006:qkq`, found \"a<2006qkq\" at line 2, column 4
+  |
+2 | px{a<2006qkq,m>2090:A,rfg}
+  |    ^^^^^^^^^"
+        );
+
+        let err = "in{x<10:A,foo}\n\n{x=1,m=2,a=3,s=4}"
+            .parse::<Game>()
+            .unwrap_err();
+        assert!(matches!(err, AocError::InvalidInput(_)));
+        assert_eq!(
+            err.to_string(),
+            "invalid input: workflow `in` jumps to `foo`, which doesn't exist"
+        );
+    }
 }
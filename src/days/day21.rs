@@ -0,0 +1,233 @@
+use colored::Colorize;
+use colorgrad::magma;
+use log::{debug, log_enabled, trace, Level};
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Write;
+
+use crate::data_structures::{Grid2D, NeighborhoodShape};
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+    type Input = Grid2D<u8>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        parse_grid(data)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input, 64).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input, 26_501_365).into()
+    }
+}
+
+pub trait GetDistances {
+    fn get_distances(&self, max_steps: usize) -> Grid2D<isize>;
+}
+
+impl GetDistances for Grid2D<u8> {
+    /// The fewest steps to every garden plot from the start, or -1 for the ones out of reach.
+    fn get_distances(&self, max_steps: usize) -> Grid2D<isize> {
+        let mut distances = Grid2D::new(self.rows, self.columns, -1_isize);
+        let mut seen = std::collections::HashSet::new();
+
+        let start = self
+            .position_of(&b'S')
+            .expect("parse_grid checks that there's a start");
+        let mut queue = VecDeque::new();
+
+        queue.push_back((start, 0isize));
+
+        while let Some((position, dist)) = queue.pop_front() {
+            if seen.contains(&position) {
+                continue;
+            }
+            distances[position] = dist;
+            seen.insert(position);
+            // check if neighbors already visited once, skip if so.
+            self.neighbors(position, NeighborhoodShape::Plus)
+                .filter(|&(_, _, &value)| value != b'#')
+                .for_each(|(neighbor, _, _)| {
+                    if !seen.contains(&neighbor) && dist < max_steps as isize {
+                        queue.push_back((neighbor, 1 + dist));
+                    }
+                })
+        }
+
+        distances
+    }
+}
+
+pub fn parse_grid(data: &str) -> Result<Grid2D<u8>, AocError> {
+    let grid: Grid2D<u8> = data.parse()?;
+    if grid.position_of(&b'S').is_none() {
+        return Err(AocError::InvalidInput(
+            "there's no starting position `S` on the map".to_string(),
+        ));
+    }
+    Ok(grid)
+}
+
+/// Log the distances as a heat map, from dark for the start to bright for the farthest tiles.
+fn debug_grid_distances(grid: &Grid2D<isize>) {
+    if !log_enabled!(Level::Trace) {
+        return;
+    }
+
+    let grad = magma();
+    let max_distance = grid.iter().map(|(_, &value)| value).max().unwrap();
+
+    let mut heat_map = String::new();
+    for row in grid.iter_rows() {
+        for &value in row.iter() {
+            let color = {
+                if value == -1 {
+                    colored::CustomColor { r: 0, g: 0, b: 0 }
+                } else {
+                    let scale = (value + 1) as f64 / (max_distance + 1) as f64;
+                    let colorgrad_color = grad.at(scale).to_rgba8();
+                    colored::CustomColor {
+                        r: colorgrad_color[0],
+                        g: colorgrad_color[1],
+                        b: colorgrad_color[2],
+                    }
+                }
+            };
+            if value == -1 {
+                _ = write!(heat_map, "{:02}", "#".custom_color(color));
+            } else {
+                _ = write!(heat_map, "{:02}", value.to_string().custom_color(color));
+            }
+        }
+        heat_map.push('\n');
+    }
+    trace!("distances:\n{heat_map}");
+}
+
+/// Observation: If we reach a tile in an odd (even) number of steps, all routes to that tile take odd (even) number of steps.
+/// Any tile reachable at a smaller odd (even) number of steps is also reachable at a larger odd (even) number of steps.
+/// Thus, after X steps, only those tiles are reachable that are at taxicab distance at most X and of the same parity as X.
+pub fn solve_part1(grid: &Grid2D<u8>, steps: usize) -> usize {
+    let distances = grid.get_distances(steps);
+    debug_grid_distances(&distances);
+
+    distances
+        .iter()
+        .filter(|&(_, &v)| v >= 0)
+        .filter(|&(_, &value)| value <= steps as isize && (value as usize % 2 == steps % 2))
+        .count()
+}
+
+/// How many garden plots can be reached in exactly `steps` steps on the map repeated infinitely,
+/// by walking it one step at a time.
+fn reachable_on_tiling(grid: &Grid2D<u8>, steps: usize) -> usize {
+    let tiling = grid.tiled();
+    let start = grid
+        .position_of(&b'S')
+        .expect("parse_grid checks that there's a start");
+    let start = (start.0 as isize, start.1 as isize);
+
+    let mut seen = HashSet::from([start]);
+    let mut frontier = vec![start];
+    let mut reachable = usize::from(steps.is_multiple_of(2));
+
+    for step in 1..=steps {
+        frontier = frontier
+            .into_iter()
+            .flat_map(|position| tiling.neighbors(position, NeighborhoodShape::Plus))
+            .filter(|&(_, _, &value)| value != b'#')
+            .map(|(neighbor, _, _)| neighbor)
+            .filter(|&neighbor| seen.insert(neighbor))
+            .collect();
+        // Same observation as for part 1: every plot first reached at the right parity stays reachable.
+        if step % 2 == steps % 2 {
+            reachable += frontier.len();
+        }
+    }
+    reachable
+}
+
+/// Whether the map is square with the start in the middle, and the row and column through the start
+/// as well as the border are free of rocks, as they are in the puzzle inputs.
+/// Then walking across a tile takes exactly its width, so the reachable area grows by whole tiles,
+/// quadratically, every time the steps go up by the width.
+fn has_clear_lanes(grid: &Grid2D<u8>) -> bool {
+    fn clear<'a>(mut cells: impl Iterator<Item = &'a u8>) -> bool {
+        cells.all(|&cell| cell != b'#')
+    }
+
+    let size = grid.rows;
+    let center = size / 2;
+    grid.columns == size
+        && size % 2 == 1
+        && grid.position_of(&b'S') == Some((center, center))
+        && [0, center, size - 1]
+            .into_iter()
+            .all(|idx| clear(grid.row(idx).iter()) && clear(grid.column(idx)))
+}
+
+/// On maps with clear lanes, the plots reachable after `remainder + n * size` steps are a quadratic in `n`,
+/// so three walks that are short enough to take are enough to get there.
+/// Other maps have to be walked all the way.
+pub fn solve_part2(grid: &Grid2D<u8>, steps: usize) -> usize {
+    let size = grid.rows;
+    if !has_clear_lanes(grid) || steps < 3 * size {
+        return reachable_on_tiling(grid, steps);
+    }
+
+    let (n, remainder) = (steps / size, steps % size);
+    let [y0, y1, y2] = [0, 1, 2].map(|k| reachable_on_tiling(grid, remainder + k * size));
+    debug!("reachable after {remainder} + k * {size} steps for k = 0, 1, 2: {y0}, {y1}, {y2}");
+
+    // Newton's forward differences: f(n) = y0 + n * Δ + n * (n - 1) / 2 * Δ².
+    let first_difference = y1 - y0;
+    let second_difference = y2 + y0 - 2 * y1;
+    y0 + n * first_difference + n * (n - 1) / 2 * second_difference
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_grid, reachable_on_tiling, solve_part1, solve_part2};
+
+    #[test]
+    fn part1() {
+        let data = include_str!("../../fixtures/examples/21-1.in");
+        let grid = parse_grid(data).unwrap();
+        assert_eq!(solve_part1(&grid, 6), 16);
+        assert_eq!(solve_part2(&grid, 6), 16);
+        assert_eq!(solve_part2(&grid, 10), 50);
+        assert_eq!(solve_part2(&grid, 50), 1594);
+        assert_eq!(solve_part2(&grid, 100), 6536);
+        assert_eq!(solve_part2(&grid, 500), 167004);
+    }
+
+    #[test]
+    #[ignore = "walks thousands of steps on the example, which doesn't have clear lanes"]
+    fn part2_long_walks() {
+        let data = include_str!("../../fixtures/examples/21-1.in");
+        let grid = parse_grid(data).unwrap();
+        assert_eq!(solve_part2(&grid, 1000), 668697);
+        assert_eq!(solve_part2(&grid, 5000), 16733044);
+    }
+
+    #[test]
+    fn part2_clear_lanes() {
+        let data = r".......
+.#...#.
+..#.#..
+...S...
+..#....
+.#...#.
+.......";
+        let grid = parse_grid(data).unwrap();
+        for steps in [3 + 7 * 3, 3 + 7 * 10, 5 + 7 * 12] {
+            assert_eq!(solve_part2(&grid, steps), reachable_on_tiling(&grid, steps));
+        }
+    }
+}
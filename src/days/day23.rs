@@ -1,77 +1,78 @@
-use std::cmp::Reverse;
-use std::str::FromStr;
-use std::collections::{HashSet, VecDeque, BinaryHeap};
-use colored::ColoredString;
+use log::debug;
 use std::collections::HashMap;
+use std::collections::{HashSet, VecDeque};
+use std::str::FromStr;
+
+use crate::data_structures::{Grid2D, Grid2DParseError};
+use crate::diagnostics::Location;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
 
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+    type Input = Trails;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(data.parse()?)
+    }
 
-fn main() {
-    let data = include_str!("../../data/23.in");
-    let trails = data.parse::<Trails>().unwrap();
-    println!("part 1: {}", solve_part1(&trails));
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
 }
 
 type Coord = (isize, isize);
 
 pub trait VisitBfs<N> {
-
     fn on_node_discovered(&mut self, node: N, parent: Option<N>, breadth: usize);
-    fn is_goal(&self, node: N) -> bool { false }
+    fn is_goal(&self, _node: N) -> bool {
+        false
+    }
 }
 
-
 pub trait VisitDfs<N> {
-
-    fn on_node_started(&mut self, node: N, parent: Option<N>, depth: usize) {}
+    fn on_node_started(&mut self, _node: N, _parent: Option<N>, _depth: usize) {}
 
     /// The entire subtree rooted at this node has been visited at this point.
-    fn on_node_finished(&mut self, node: N, depth: usize) {}
-    fn on_goal_reached(&mut self, node: N, depth: usize) {
-
-    }
+    fn on_node_finished(&mut self, _node: N, _depth: usize) {}
+    fn on_goal_reached(&mut self, _node: N, _depth: usize) {}
     fn is_goal(&self, node: N) -> bool;
 }
 
 #[derive(Debug, Clone)]
-pub struct LongestPathIterator<'trails> {
-    trails: &'trails Trails,
-    start: Coord,
+pub struct LongestPathIterator {
     end: Coord,
     longest_path_length: usize,
 }
 
-impl<'trails> LongestPathIterator<'trails> {
-    pub fn new(start: Coord, end: Coord, trails: &'trails Trails) -> Self {
+impl LongestPathIterator {
+    pub fn new(end: Coord) -> Self {
         Self {
-            trails,
-            start,
             end,
             longest_path_length: 0,
         }
     }
 }
 
-impl VisitDfs<Coord> for LongestPathIterator<'_> {
+impl VisitDfs<Coord> for LongestPathIterator {
     fn is_goal(&self, node: Coord) -> bool {
         node == self.end
     }
 
-    fn on_node_finished(&mut self, node: Coord, depth: usize) {
-        
-    }
-    fn on_goal_reached(&mut self, node: Coord, depth: usize) {
+    fn on_goal_reached(&mut self, _node: Coord, depth: usize) {
         self.longest_path_length = self.longest_path_length.max(depth);
     }
 }
 
-
 #[derive(Debug, Clone)]
 pub struct JunctionMapBuilder<'trails> {
     trails: &'trails Trails,
     start: Coord,
     end: Coord,
     parent_map: HashMap<Coord, Option<Coord>>,
-    junctions: HashSet<Coord>
+    junctions: HashSet<Coord>,
 }
 
 pub type CompressedMap = HashMap<Coord, HashMap<Coord, usize>>;
@@ -83,7 +84,7 @@ impl<'a> JunctionMapBuilder<'a> {
             start,
             end,
             parent_map: Default::default(),
-            junctions: Default::default()
+            junctions: Default::default(),
         }
     }
 
@@ -96,10 +97,10 @@ impl<'a> JunctionMapBuilder<'a> {
 
         // TODO:
         // Starting at junctions and walking backwards towards the start node,
-        // find all the intermediate junctions and track their distances 
+        // find all the intermediate junctions and track their distances
         // using the parent_map.
         for &junction in self.junctions.iter() {
-            let distances_from_junction = compressed.entry(junction).or_insert(Default::default());
+            let distances_from_junction = compressed.entry(junction).or_default();
 
             let mut current = junction;
             let mut dist = 0;
@@ -113,7 +114,7 @@ impl<'a> JunctionMapBuilder<'a> {
         }
 
         for (key, val) in compressed.iter() {
-            println!("{:?}: {:?}", key, val);
+            debug!("{:?}: {:?}", key, val);
         }
         // Now invert this map to get distances from source to target instead of "to source from target".
         let mut compressed_inv = HashMap::new();
@@ -121,26 +122,25 @@ impl<'a> JunctionMapBuilder<'a> {
         for (target, value) in compressed.into_iter() {
             for (source, dist) in value.into_iter() {
                 compressed_inv
-                .entry(source)
-                .and_modify(|mapping: &mut HashMap<Coord, usize>| {
-                    mapping.entry(target).and_modify(|d| *d = dist).or_insert(dist);
-                })
-                .or_insert_with(|| {
-                    let mut _m = HashMap::new();
-                    _m.insert(target, dist);
-                    _m
-                });
+                    .entry(source)
+                    .and_modify(|mapping: &mut HashMap<Coord, usize>| {
+                        mapping
+                            .entry(target)
+                            .and_modify(|d| *d = dist)
+                            .or_insert(dist);
+                    })
+                    .or_insert_with(|| {
+                        let mut _m = HashMap::new();
+                        _m.insert(target, dist);
+                        _m
+                    });
             }
         }
-        println!("");
-        
         compressed_inv
     }
 }
 
-
 impl VisitBfs<Coord> for JunctionMapBuilder<'_> {
-
     fn on_node_discovered(&mut self, node: Coord, parent: Option<Coord>, _breadth: usize) {
         self.parent_map.insert(node, parent);
 
@@ -151,35 +151,31 @@ impl VisitBfs<Coord> for JunctionMapBuilder<'_> {
 }
 
 impl Trails {
-
     pub fn neighbors_part1(&self, pos: Coord) -> HashSet<Coord> {
         let (row, col) = (pos.0, pos.1);
-        let deltas = 
-            match self.0[pos.0 as usize][pos.1 as usize] {
-                b'>' => {
-                    vec![(0, 1)]
-                },
-                b'<' => {
-                    vec![(0, -1)]
-                },
-                b'^' => {
-                    vec![(-1, 0)]
-                },
-                b'v' => {
-                    vec![(1, 0)]
-                },
-                _ => {
-                    vec![(-1, 0), (1, 0), (0, -1), (0, 1)]
-                }
-            };
+        let deltas = match self.0[(pos.0 as usize, pos.1 as usize)] {
+            b'>' => {
+                vec![(0, 1)]
+            }
+            b'<' => {
+                vec![(0, -1)]
+            }
+            b'^' => {
+                vec![(-1, 0)]
+            }
+            b'v' => {
+                vec![(1, 0)]
+            }
+            _ => {
+                vec![(-1, 0), (1, 0), (0, -1), (0, 1)]
+            }
+        };
 
         let mut res = HashSet::<(isize, isize)>::new();
         for (dx, dy) in deltas {
             let (cx, cy) = (row + dx, col + dy);
-            if 0 <= cx && cx < self.0.len() as isize && 0 <= cy && cy < self.0[0].len() as isize {
-                if self.0[cx as usize][cy as usize] != b'#' {
-                    res.insert((cx, cy));
-                }
+            if matches!(self.0.at((cx, cy)), Some(&tile) if tile != b'#') {
+                res.insert((cx, cy));
             }
         }
         res
@@ -192,27 +188,20 @@ impl Trails {
         let mut res = HashSet::<(isize, isize)>::new();
         for (dx, dy) in deltas {
             let (cx, cy) = (row + dx, col + dy);
-            if 0 <= cx && cx < self.0.len() as isize && 0 <= cy && cy < self.0[0].len() as isize {
-                if self.0[cx as usize][cy as usize] != b'#' {
-                    res.insert((cx, cy));
-                }
+            if matches!(self.0.at((cx, cy)), Some(&tile) if tile != b'#') {
+                res.insert((cx, cy));
             }
         }
         res
     }
 }
 
-
-pub fn bfs<V, N, NeighborFn, I>(
-    start: N,
-    neighbors: NeighborFn,
-    visitor: &mut V,
-)
-where   
+pub fn bfs<V, N, NeighborFn, I>(start: N, neighbors: NeighborFn, visitor: &mut V)
+where
     V: VisitBfs<N>,
     NeighborFn: Fn(N) -> I,
     N: std::hash::Hash + Eq + Copy,
-    I: IntoIterator<Item=N>
+    I: IntoIterator<Item = N>,
 {
     let mut deque = VecDeque::new();
     deque.push_back((start, None, 0usize));
@@ -233,16 +222,15 @@ where
 }
 
 fn _dfs<V, NeighborFn, I>(
-    start: Coord, 
+    start: Coord,
     depth: usize,
     neighbors: &NeighborFn,
     visitor: &mut V,
-    seen: &mut HashSet<Coord>
-) 
-where
+    seen: &mut HashSet<Coord>,
+) where
     V: VisitDfs<Coord>,
     NeighborFn: Fn(Coord) -> I,
-    I: IntoIterator<Item=Coord>,
+    I: IntoIterator<Item = Coord>,
 {
     if visitor.is_goal(start) {
         visitor.on_goal_reached(start, depth);
@@ -258,81 +246,71 @@ where
     }
 }
 
-
-pub fn dfs<V, NeighborFn, I>(
-    start: Coord, 
-    neighbors: NeighborFn,
-    visitor: &mut V,
-)
+pub fn dfs<V, NeighborFn, I>(start: Coord, neighbors: NeighborFn, visitor: &mut V)
 where
     V: VisitDfs<Coord>,
     NeighborFn: Fn(Coord) -> I,
-    I: IntoIterator<Item=Coord>
+    I: IntoIterator<Item = Coord>,
 {
     let mut seen = HashSet::new();
     _dfs(start, 0, &neighbors, visitor, &mut seen)
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Trails(Vec<Vec<u8>>);
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trails(Grid2D<u8>);
 
 impl FromStr for Trails {
-    type Err = String;
+    type Err = Grid2DParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut island = vec![];
-        for line in s.lines() {
-            let mut row = vec![];
-            for entry in line.chars() {
-                row.push(entry as u8);
-            }
-            island.push(row);
+        let island: Grid2D<u8> = s.parse()?;
+        if let Some(((row, col), &entry)) = island
+            .iter()
+            .find(|(_, &entry)| !b"#.<>^v".contains(&entry))
+        {
+            return Err(Grid2DParseError::UnknownCell {
+                value: entry as char,
+                location: Location::new(row + 1, col + 1, 1, s.lines().nth(row).unwrap()),
+            });
         }
         Ok(Self(island))
     }
 }
 
-
 pub fn solve_part1(trails: &Trails) -> usize {
     let start: Coord = (0, 1);
-    let end: Coord = (trails.0.len() as isize - 1, trails.0[0].len() as isize - 2);
-    let mut longest_path_finder = LongestPathIterator::new(start, end, trails);
+    let end: Coord = (trails.0.rows as isize - 1, trails.0.columns as isize - 2);
+    let mut longest_path_finder = LongestPathIterator::new(end);
 
     dfs(
-        start, 
-        |node| trails.neighbors_part1(node), 
+        start,
+        |node| trails.neighbors_part1(node),
         &mut longest_path_finder,
     );
 
     longest_path_finder.longest_path_length
 }
 
-
 pub fn solve_part2(trails: &Trails) -> usize {
     let start: Coord = (0, 1);
-    let end: Coord = (trails.0.len() as isize - 1, trails.0[0].len() as isize - 2);
+    let end: Coord = (trails.0.rows as isize - 1, trails.0.columns as isize - 2);
 
     let mut builder = JunctionMapBuilder::new(start, end, trails);
 
-    bfs(
-        start, 
-        |node| trails.neighbors_part2(node), 
-        &mut builder
-    );
+    bfs(start, |node| trails.neighbors_part2(node), &mut builder);
 
     let compressed = builder.compress();
 
     // println!("{:?}", compressed);
 
     for (key, value) in compressed.iter() {
-        println!("{:?}: {:?}", key, value);
+        debug!("{:?}: {:?}", key, value);
     }
 
-
     // let start: Coord = (0, 1);
-    // let end: Coord = (trails.0.len() as isize - 1, trails.0[0].len() as isize - 2);
+    // let end: Coord = (trails.0.rows as isize - 1, trails.0.columns as isize - 2);
 
     // let mut junction_finder = JunctionMapBuilder::new(start, end, trails);
-    
+
     // bfs(start, |node| trails.neighbors_part2(node), &mut junction_finder);
 
     // let compressed = junction_finder.compress();
@@ -374,6 +352,5 @@ mod tests {
         let trails = data.parse::<Trails>().unwrap();
         assert_eq!(solve_part1(&trails), 94);
         assert_eq!(solve_part2(&trails), 0);
-
     }
-}
\ No newline at end of file
+}
@@ -1,12 +1,37 @@
 use colored::Colorize;
+use log::debug;
 use nalgebra::*;
 use std::fmt::Display;
 
-fn main() {
-    let data = include_str!("../../data/24.in");
-    let res = solve_part1(data, 200000000000000, 400000000000000, false);
-    println!("part 1: {}", res);
-    println!("part 2: {}", solve_part2(data));
+use crate::error::{AocError, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+    type Input = Vec<Hailstone>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        let hailstones = data
+            .lines()
+            .map(|line| parse_hailstone(line).map_err(|err| err.locate(data, line)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if rock_throw_system(&hailstones).is_none() {
+            return Err(AocError::InvalidInput(
+                "the first three hailstones don't pin down a single throw of the rock".to_string(),
+            ));
+        }
+        Ok(hailstones)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input, 200000000000000, 400000000000000).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        Answer::Integer(solve_part2(input) as i128)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -42,22 +67,14 @@ impl Hailstone {
     }
 
     /// Determine if two linear trajectories in R^2 intersect in a given box.
-    pub fn crosses_2d(
-        &self,
-        other: &Hailstone,
-        min_pos: isize,
-        max_pos: isize,
-        debug: bool,
-    ) -> bool {
-        if debug {
-            println!("Hailstone A: {}", self);
-            println!("Hailstone B: {}", other);
-        }
+    pub fn crosses_2d(&self, other: &Hailstone, min_pos: isize, max_pos: isize) -> bool {
+        debug!("Hailstone A: {}", self);
+        debug!("Hailstone B: {}", other);
 
         // Are trajectories parallel? If so, then they don't cross each other unless they start at the same point.
         if self.slope_2d() == other.slope_2d() {
-            if self.pos_2d() != other.pos_2d() && debug {
-                println!("Hailstones' paths are parallel; they never intersect.");
+            if self.pos_2d() != other.pos_2d() {
+                debug!("Hailstones' paths are parallel; they never intersect.");
             }
             return self.pos_2d() == other.pos_2d();
         }
@@ -105,19 +122,13 @@ impl Hailstone {
 
         if t0 < 0.0 {
             if t1 < 0.0 {
-                if debug {
-                    println!("Hailstones' paths crossed in the past for both hailstones.");
-                }
+                debug!("Hailstones' paths crossed in the past for both hailstones.");
                 return false;
             }
-            if debug {
-                println!("Hailstones' paths crossed in the past for hailstone A.");
-            }
+            debug!("Hailstones' paths crossed in the past for hailstone A.");
             return false;
         } else if t1 < 0.0 {
-            if debug {
-                println!("Hailstones' paths crossed in the past for hailstone B.");
-            }
+            debug!("Hailstones' paths crossed in the past for hailstone B.");
             return false;
         }
 
@@ -128,23 +139,16 @@ impl Hailstone {
         let time_bound_valid =
             (pos.0.min(pos.1) >= min_pos as f64) && (pos.0.max(pos.1) <= max_pos as f64);
 
-        if time_bound_valid {
-            if debug {
-                println!(
-                    "Hailstones' paths will cross {} the test area (at x={:.3}, y={:.3}).",
-                    "inside".bold().green(),
-                    pos.0,
-                    pos.1
-                );
-            }
-        } else if debug {
-            println!(
-                "Hailstones' paths will cross {} the test area (at x={:.3}, y={:.3}).",
-                "outside".red(),
-                pos.0,
-                pos.1
-            );
-        }
+        debug!(
+            "Hailstones' paths will cross {} the test area (at x={:.3}, y={:.3}).",
+            if time_bound_valid {
+                "inside".bold().green()
+            } else {
+                "outside".red()
+            },
+            pos.0,
+            pos.1
+        );
 
         time_bound_valid
     }
@@ -156,48 +160,45 @@ impl Hailstone {
     }
 }
 
-pub fn parse_hailstone(s: &str) -> Hailstone {
-    let (left, right) = s.split_once('@').unwrap();
-    let left = left.trim();
-    let right = right.trim();
-
-    let position = left
-        .split(", ")
-        .map(str::trim)
-        .map(str::parse::<isize>)
-        .collect::<Result<std::vec::Vec<isize>, _>>()
-        .unwrap();
-    let velocity = right
-        .split(", ")
-        .map(str::trim)
-        .map(str::parse::<isize>)
-        .collect::<Result<std::vec::Vec<isize>, _>>()
-        .unwrap();
-
-    Hailstone {
-        x0: position[0],
-        y0: position[1],
-        z0: position[2],
-        vx: velocity[0],
-        vy: velocity[1],
-        vz: velocity[2],
-    }
+pub fn parse_hailstone(s: &str) -> Result<Hailstone, ParseError> {
+    let (left, right) = s
+        .split_once('@')
+        .ok_or_else(|| ParseError::new("a hailstone like `19, 13, 30 @ -2, 1, -2`", s))?;
+
+    let vector = |numbers: &str| -> Result<[isize; 3], ParseError> {
+        let numbers = numbers.trim();
+        numbers
+            .split(',')
+            .map(str::trim)
+            .map(|number| {
+                number
+                    .parse()
+                    .map_err(|_| ParseError::new("a number", number))
+            })
+            .collect::<Result<Vec<isize>, _>>()?
+            .try_into()
+            .map_err(|_| ParseError::new("three numbers", numbers))
+    };
+    let [x0, y0, z0] = vector(left)?;
+    let [vx, vy, vz] = vector(right)?;
+
+    Ok(Hailstone {
+        x0,
+        y0,
+        z0,
+        vx,
+        vy,
+        vz,
+    })
 }
 
-pub fn solve_part1(data: &str, min_pos: isize, max_pos: isize, debug: bool) -> usize {
-    let hailstones = data
-        .lines()
-        .map(parse_hailstone)
-        .collect::<std::vec::Vec<_>>();
+pub fn solve_part1(hailstones: &[Hailstone], min_pos: isize, max_pos: isize) -> usize {
     let mut counter = 0;
     for i in 0..hailstones.len() {
         for j in i + 1..hailstones.len() {
-            if hailstones[i].crosses_2d(&hailstones[j], min_pos, max_pos, debug) {
+            if hailstones[i].crosses_2d(&hailstones[j], min_pos, max_pos) {
                 counter += 1;
             }
-            if debug {
-                println!();
-            }
         }
     }
     counter
@@ -215,18 +216,16 @@ pub fn build_skew_symmetric_matrix(v: &Vector3<f64>) -> Matrix3<f64> {
     Matrix3::new(0., -v[2], v[1], v[2], 0., -v[0], -v[1], v[0], 0.)
 }
 
-pub fn solve_part2(data: &str) -> f64 {
-    let hailstones = data
-        .lines()
-        .map(parse_hailstone)
-        .collect::<std::vec::Vec<_>>();
-
-    // Pick the first three hailstones to compute the unique position/velocity for the rock.
-    let (i, j, k) = (0, 1, 2);
-
-    let (p0, v0) = hailstones[i].position_and_velocity();
-    let (p1, v1) = hailstones[j].position_and_velocity();
-    let (p2, v2) = hailstones[k].position_and_velocity();
+/// The linear system that the position and velocity of the rock solve, as the inverse of its
+/// coefficient matrix and its right-hand side. It's made of the first three hailstones,
+/// so it's `None` when there aren't three of them or they don't pin down a single throw.
+pub fn rock_throw_system(hailstones: &[Hailstone]) -> Option<(Matrix6<f64>, Vector6<f64>)> {
+    let [h0, h1, h2, ..] = hailstones else {
+        return None;
+    };
+    let (p0, v0) = h0.position_and_velocity();
+    let (p1, v1) = h1.position_and_velocity();
+    let (p2, v2) = h2.position_and_velocity();
 
     // The solution comes from the observation that (P - Pi) X (V - Vi) = 0,
     // and then simplifying to get
@@ -285,7 +284,7 @@ pub fn solve_part2(data: &str) -> f64 {
     );
 
     if !coefficient_matrix.try_inverse_mut() {
-        panic!("coefficient matrix isn't invertible, so no solutions exist.");
+        return None;
     }
 
     let row_vector_upper = p1.cross(&v1) - p0.cross(&v0);
@@ -299,8 +298,14 @@ pub fn solve_part2(data: &str) -> f64 {
         row_vector_lower.y,
         row_vector_lower.z,
     );
+    Some((coefficient_matrix, row_vector))
+}
+
+pub fn solve_part2(hailstones: &[Hailstone]) -> f64 {
+    let (inverse, row_vector) =
+        rock_throw_system(hailstones).expect("parsing checks that the rock can be thrown");
 
-    let rock_pos_vel = coefficient_matrix * row_vector;
+    let rock_pos_vel = inverse * row_vector;
     let arr = rock_pos_vel.as_ref();
     let mut pos = Vector3::from_row_slice(&arr[..3]);
 
@@ -330,7 +335,21 @@ mod tests {
 20, 25, 34 @ -2, -2, -4
 12, 31, 28 @ -1, -2, -1
 20, 19, 15 @  1, -5, -3";
-        assert_eq!(solve_part1(data, 7, 27, true), 2);
-        assert_eq!(solve_part2(data), 47.);
+        let hailstones = Day24::parse(data).unwrap();
+        assert_eq!(solve_part1(&hailstones, 7, 27), 2);
+        assert_eq!(solve_part2(&hailstones), 47.);
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(matches!(
+            Day24::parse("19, 13, 30 @ -2, 1, -2\n18, 19 @ -1, -1, -2"),
+            Err(AocError::Parse(_))
+        ));
+        // The rock can be thrown through any two of them.
+        assert!(matches!(
+            Day24::parse("19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2"),
+            Err(AocError::InvalidInput(_))
+        ));
     }
 }
@@ -1,34 +1,50 @@
+use log::debug;
 use petgraph::{
     algo::minimum_cut,
     graph::UnGraph,
     visit::{
-        EdgeRef, GraphBase, IntoEdgeReferences, IntoNodeIdentifiers,
-        NodeCount, NodeIndexable,
+        EdgeRef, GraphBase, IntoEdgeReferences, IntoNodeIdentifiers, NodeCount, NodeIndexable,
     },
     Graph, Undirected,
 };
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
-pub fn main() {
-    let data = include_str!("../../data/25.in");
+use crate::error::{AocError, ParseError};
+use crate::solution::{Answer, Solution};
 
-    let graph = build_graph(data);
-    println!("part 1: {}", solve(&graph));
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+    type Input = Graph<String, (), Undirected>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(build_graph(data)?)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve(input).into()
+    }
 }
 
-pub fn build_graph(data: &str) -> petgraph::Graph<&str, (), Undirected> {
+pub fn build_graph(data: &str) -> Result<petgraph::Graph<String, (), Undirected>, ParseError> {
     let mut hmap = HashMap::<&str, Vec<_>>::new();
 
-    data.lines().for_each(|line| {
-        let (source, neighbors) = line.split_once(": ").unwrap();
+    for line in data.lines() {
+        let (source, neighbors) = line.split_once(": ").ok_or_else(|| {
+            ParseError::new("a component and its connections like `jqt: rhn xhk`", line)
+                .locate(data, line)
+        })?;
 
-        let neighbor_ids = neighbors.trim().split_whitespace();
+        let neighbor_ids = neighbors.split_whitespace();
 
-        hmap.entry(source)
-            .and_modify(|_| panic!("repeat entries?"))
-            .or_insert(neighbor_ids.collect());
-    });
+        if hmap.insert(source, neighbor_ids.collect()).is_some() {
+            return Err(
+                ParseError::new("a component that isn't listed yet", source).locate(data, line)
+            );
+        }
+    }
 
     let mut unique_nodes = HashSet::new();
     let mut node_ids_to_names = HashMap::new();
@@ -45,7 +61,7 @@ pub fn build_graph(data: &str) -> petgraph::Graph<&str, (), Undirected> {
     let mut graph = UnGraph::default();
 
     for node in unique_nodes {
-        let node_id = graph.add_node(node);
+        let node_id = graph.add_node(node.to_string());
         node_names_to_ids.insert(node, node_id);
         node_ids_to_names.insert(node_id, node);
     }
@@ -58,7 +74,7 @@ pub fn build_graph(data: &str) -> petgraph::Graph<&str, (), Undirected> {
         }
     });
 
-    graph
+    Ok(graph)
 }
 
 pub fn get_connected_components<G>(graph: G) -> Vec<Vec<G::NodeId>>
@@ -85,14 +101,14 @@ where
     label_map.values().cloned().collect::<Vec<_>>()
 }
 
-pub fn solve(graph: &Graph<&str, (), Undirected>) -> usize {
+pub fn solve(graph: &Graph<String, (), Undirected>) -> usize {
     let (edges, _) = minimum_cut(graph, |_| 1);
     let mut graph = graph.clone();
     assert_eq!(edges.len(), 3);
 
     for edge in edges {
         let (start, end) = graph.edge_endpoints(edge).unwrap();
-        println!("Disconnect wires: {}/{}", &graph[start], &graph[end]);
+        debug!("Disconnect wires: {}/{}", &graph[start], &graph[end]);
         graph.remove_edge(edge);
     }
 
@@ -121,7 +137,7 @@ lsr: lhk
 rzs: qnr cmg lsr rsh
 frs: qnr lhk lsr";
 
-        let graph = build_graph(data);
+        let graph = build_graph(data).unwrap();
         assert_eq!(solve(&graph), 9 * 6);
     }
 }
@@ -0,0 +1,129 @@
+//! Solutions for the individual days, each behind its own `day-NN` feature
+//! so that heavier dependencies are only pulled in when that day is built.
+
+#[cfg(feature = "day-01")]
+pub mod day01;
+#[cfg(feature = "day-02")]
+pub mod day02;
+#[cfg(feature = "day-03")]
+pub mod day03;
+#[cfg(feature = "day-04")]
+pub mod day04;
+#[cfg(feature = "day-05")]
+pub mod day05;
+#[cfg(feature = "day-06")]
+pub mod day06;
+#[cfg(feature = "day-07")]
+pub mod day07;
+#[cfg(feature = "day-08")]
+pub mod day08;
+#[cfg(feature = "day-09")]
+pub mod day09;
+#[cfg(feature = "day-10")]
+pub mod day10;
+#[cfg(feature = "day-11")]
+pub mod day11;
+#[cfg(feature = "day-12")]
+pub mod day12;
+#[cfg(feature = "day-13")]
+pub mod day13;
+#[cfg(feature = "day-14")]
+pub mod day14;
+#[cfg(feature = "day-15")]
+pub mod day15;
+#[cfg(feature = "day-16")]
+pub mod day16;
+#[cfg(feature = "day-17")]
+pub mod day17;
+#[cfg(feature = "day-18")]
+pub mod day18;
+#[cfg(feature = "day-19")]
+pub mod day19;
+#[cfg(feature = "day-21")]
+pub mod day21;
+#[cfg(feature = "day-23")]
+pub mod day23;
+#[cfg(feature = "day-24")]
+pub mod day24;
+#[cfg(feature = "day-25")]
+pub mod day25;
+
+use crate::error::AocError;
+use crate::solution::{self, Part, Run, Solution};
+
+/// A day that the runner knows how to solve, given the raw puzzle input.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub number: u32,
+    pub run: fn(&str, &[Part]) -> Result<Run, AocError>,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Self {
+        Day {
+            number: S::DAY,
+            run: solution::run::<S>,
+        }
+    }
+}
+
+/// Get all the days compiled into this build, ordered by day number.
+#[allow(clippy::vec_init_then_push)]
+pub fn registry() -> Vec<Day> {
+    #[allow(unused_mut)]
+    let mut days = vec![];
+
+    #[cfg(feature = "day-01")]
+    days.push(Day::of::<day01::Day01>());
+    #[cfg(feature = "day-02")]
+    days.push(Day::of::<day02::Day02>());
+    #[cfg(feature = "day-03")]
+    days.push(Day::of::<day03::Day03>());
+    #[cfg(feature = "day-04")]
+    days.push(Day::of::<day04::Day04>());
+    #[cfg(feature = "day-05")]
+    days.push(Day::of::<day05::Day05>());
+    #[cfg(feature = "day-06")]
+    days.push(Day::of::<day06::Day06>());
+    #[cfg(feature = "day-07")]
+    days.push(Day::of::<day07::Day07>());
+    #[cfg(feature = "day-08")]
+    days.push(Day::of::<day08::Day08>());
+    #[cfg(feature = "day-09")]
+    days.push(Day::of::<day09::Day09>());
+    #[cfg(feature = "day-10")]
+    days.push(Day::of::<day10::Day10>());
+    #[cfg(feature = "day-11")]
+    days.push(Day::of::<day11::Day11>());
+    #[cfg(feature = "day-12")]
+    days.push(Day::of::<day12::Day12>());
+    #[cfg(feature = "day-13")]
+    days.push(Day::of::<day13::Day13>());
+    #[cfg(feature = "day-14")]
+    days.push(Day::of::<day14::Day14>());
+    #[cfg(feature = "day-15")]
+    days.push(Day::of::<day15::Day15>());
+    #[cfg(feature = "day-16")]
+    days.push(Day::of::<day16::Day16>());
+    #[cfg(feature = "day-17")]
+    days.push(Day::of::<day17::Day17>());
+    #[cfg(feature = "day-18")]
+    days.push(Day::of::<day18::Day18>());
+    #[cfg(feature = "day-19")]
+    days.push(Day::of::<day19::Day19>());
+    #[cfg(feature = "day-21")]
+    days.push(Day::of::<day21::Day21>());
+    #[cfg(feature = "day-23")]
+    days.push(Day::of::<day23::Day23>());
+    #[cfg(feature = "day-24")]
+    days.push(Day::of::<day24::Day24>());
+    #[cfg(feature = "day-25")]
+    days.push(Day::of::<day25::Day25>());
+
+    days
+}
+
+/// Look up a single day in the registry.
+pub fn find(number: u32) -> Option<Day> {
+    registry().into_iter().find(|day| day.number == number)
+}
@@ -0,0 +1,117 @@
+//! Pointing at the spot in the input where parsing went wrong, so a malformed
+//! puzzle input reads like a compiler error instead of a panic:
+//!
+//! ```text
+//! expected a rule like `a<2006:qkq`, found "a<2006qkq" at line 1, column 4
+//!   |
+//! 1 | px{a<2006qkq,m>2090:A,rfg}
+//!   |    ^^^^^^^^^
+//! ```
+use std::fmt::Write;
+
+/// A span on a single line of the input, along with that line so it can be shown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// Starts at 1.
+    pub line: usize,
+    /// Starts at 1, counted in characters.
+    pub column: usize,
+    /// How many characters to underline, at least 1.
+    pub width: usize,
+    pub source_line: String,
+}
+
+impl Location {
+    pub fn new(line: usize, column: usize, width: usize, source_line: &str) -> Self {
+        Self {
+            line,
+            column,
+            width: width.max(1),
+            source_line: source_line.to_string(),
+        }
+    }
+
+    /// Locate `text` on `line`, which has to be one of the lines of `source`
+    /// (e.g. as given by `source.lines()`).
+    /// When `text` isn't on the line, the whole line gets underlined.
+    pub fn of(source: &str, line: &str, text: &str) -> Self {
+        let line_number = source[..line_offset(source, line)].matches('\n').count() + 1;
+
+        match line.find(text).filter(|_| !text.is_empty()) {
+            Some(offset) => Self::new(
+                line_number,
+                line[..offset].chars().count() + 1,
+                text.chars().count(),
+                line,
+            ),
+            None => Self::new(line_number, 1, line.chars().count(), line),
+        }
+    }
+
+    /// The line with the span underlined by carets, prefixed by a gutter with the line number.
+    pub fn snippet(&self) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+
+        let mut snippet = String::new();
+        _ = writeln!(snippet, "{gutter} |");
+        _ = writeln!(snippet, "{number} | {}", self.source_line);
+        _ = write!(
+            snippet,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        );
+        snippet
+    }
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Where `line` starts in `source`. Lines that borrow from `source` are found by their address,
+/// which tells identical lines apart; anything else is searched for.
+fn line_offset(source: &str, line: &str) -> usize {
+    let start = source.as_ptr() as usize;
+    let address = line.as_ptr() as usize;
+    if address >= start && address + line.len() <= start + source.len() {
+        address - start
+    } else {
+        source.find(line).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate() {
+        let source = "ab\nab\nxyz{a<2006qkq}";
+        let lines: Vec<_> = source.lines().collect();
+
+        assert_eq!(
+            Location::of(source, lines[1], "b"),
+            Location::new(2, 2, 1, "ab")
+        );
+        assert_eq!(
+            Location::of(source, lines[2], "a<2006qkq"),
+            Location::new(3, 5, 9, lines[2])
+        );
+        // Not on the line, so the whole line is to blame.
+        assert_eq!(
+            Location::of(source, lines[0], "c"),
+            Location::new(1, 1, 2, "ab")
+        );
+    }
+
+    #[test]
+    fn snippet() {
+        let location = Location::new(12, 4, 3, "px{a<2006qkq}");
+        assert_eq!(location.to_string(), "line 12, column 4");
+        assert_eq!(location.snippet(), "   |\n12 | px{a<2006qkq}\n   |    ^^^");
+    }
+}
@@ -0,0 +1,76 @@
+//! The errors that solving a puzzle can run into, from a malformed input
+//! to a request to the Advent of Code servers that didn't go through.
+use crate::data_structures::Grid2DParseError;
+use crate::diagnostics::Location;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AocError {
+    #[error("malformed input: {0}")]
+    Parse(#[from] ParseError),
+    #[error("malformed input: {0}")]
+    Grid(#[from] Grid2DParseError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("request failed: {0}")]
+    Http(String),
+    /// The input is well-formed, but doesn't make for a puzzle that can be solved.
+    #[error("invalid input: {0}")]
+    InvalidInput(String),
+}
+
+#[cfg(any(feature = "inputs", feature = "submit", feature = "leaderboard"))]
+impl From<reqwest::Error> for AocError {
+    fn from(err: reqwest::Error) -> Self {
+        AocError::Http(err.to_string())
+    }
+}
+
+/// A piece of the input that isn't what we expected.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub struct ParseError {
+    /// What should have been there, e.g. "a rule like `a<2006:qkq`".
+    pub expected: String,
+    pub found: String,
+    /// Where `found` is in the input, when the parser knows.
+    pub location: Option<Location>,
+}
+
+impl ParseError {
+    pub fn new(expected: impl Into<String>, found: impl Into<String>) -> Self {
+        Self {
+            expected: expected.into(),
+            found: found.into(),
+            location: None,
+        }
+    }
+
+    pub fn at(self, location: Location) -> Self {
+        Self {
+            location: Some(location),
+            ..self
+        }
+    }
+
+    /// Point at `found` on `line`, which has to be one of the lines of `source`.
+    /// Errors that already know where they are keep their location.
+    pub fn locate(self, source: &str, line: &str) -> Self {
+        match self.location {
+            Some(_) => self,
+            None => {
+                let location = Location::of(source, line, &self.found);
+                self.at(location)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected {}, found {:?}", self.expected, self.found)?;
+        if let Some(location) = &self.location {
+            write!(f, " at {location}\n{}", location.snippet())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+//! The examples from the puzzle descriptions, kept as fixtures under
+//! `data/examples/` so that every day is checked against them without
+//! having to paste them into the tests by hand. The ones under
+//! `fixtures/examples/` are tracked, so they're checked on every checkout.
+//!
+//! Every example is a pair of files: `NN-k.in` with the example input and
+//! `NN-k.ans` with the answers it should produce, in the same form as the
+//! entries of `data/answers.toml`:
+//!
+//! ```toml
+//! part1 = 35
+//! part2 = 46
+//! ```
+//!
+//! Some puzzles use different parameters for the examples than for the real
+//! input (e.g. fewer steps), so the extracted answers are only a starting point:
+//! remove the parts from the `.ans` file that don't apply.
+use crate::answers::{self, AnswersError, Status};
+use crate::days;
+use crate::error::AocError;
+use crate::puzzle::Example;
+use crate::solution::{Answer, Part};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum FixtureError {
+    #[error("couldn't access {}: {source}", path.to_string_lossy())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("couldn't read the answers in {}: {source}", path.to_string_lossy())]
+    Answers { path: PathBuf, source: AnswersError },
+}
+
+fn io_error(path: &Path) -> impl FnOnce(std::io::Error) -> FixtureError + '_ {
+    move |source| FixtureError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+/// An example input for a day and the answers it should produce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub day: u32,
+    /// Tells the examples of a single day apart, starting at 1.
+    pub index: usize,
+    pub input: String,
+    pub answers: Vec<(Part, Answer)>,
+}
+
+pub fn default_examples_dir(data_dir: &Path) -> PathBuf {
+    data_dir.join("examples")
+}
+
+/// Write the examples of a day to `NN-k.in` and `NN-k.ans`.
+/// Existing fixtures are left alone (they may have been edited) unless `force` is set.
+pub fn save(
+    dir: &Path,
+    day: u32,
+    examples: &[Example],
+    force: bool,
+) -> Result<Vec<PathBuf>, FixtureError> {
+    std::fs::create_dir_all(dir).map_err(io_error(dir))?;
+
+    let mut written = vec![];
+    for (index, example) in examples.iter().enumerate() {
+        let stem = format!("{:02}-{}", day, index + 1);
+        let input_path = dir.join(format!("{stem}.in"));
+        let answers_path = dir.join(format!("{stem}.ans"));
+
+        if !force && input_path.exists() {
+            continue;
+        }
+        std::fs::write(&input_path, &example.input).map_err(io_error(&input_path))?;
+        std::fs::write(&answers_path, answers::render_day(&example.answers))
+            .map_err(io_error(&answers_path))?;
+        written.push(input_path);
+    }
+    Ok(written)
+}
+
+/// Read every fixture in the directory, ordered by day and index.
+/// A missing directory just means there are no fixtures yet.
+pub fn load(dir: &Path) -> Result<Vec<Fixture>, FixtureError> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(io_error(dir)(err)),
+    };
+
+    let mut fixtures = vec![];
+    for entry in entries {
+        let input_path = entry.map_err(io_error(dir))?.path();
+        if input_path.extension() != Some("in".as_ref()) {
+            continue;
+        }
+        let Some((day, index)) = input_path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.split_once('-'))
+            .and_then(|(day, index)| Some((day.parse().ok()?, index.parse().ok()?)))
+        else {
+            continue;
+        };
+
+        let input = std::fs::read_to_string(&input_path).map_err(io_error(&input_path))?;
+        let answers_path = input_path.with_extension("ans");
+        let answers = match std::fs::read_to_string(&answers_path) {
+            Ok(data) => answers::parse_day(&data).map_err(|source| FixtureError::Answers {
+                path: answers_path,
+                source,
+            })?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(err) => return Err(io_error(&answers_path)(err)),
+        };
+
+        fixtures.push(Fixture {
+            day,
+            index,
+            input,
+            answers,
+        });
+    }
+
+    fixtures.sort_by_key(|fixture| (fixture.day, fixture.index));
+    Ok(fixtures)
+}
+
+/// The outcome of solving a single part of an example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub part: Part,
+    pub expected: Answer,
+    pub actual: Answer,
+    pub status: Status,
+}
+
+/// Solve the parts of the example that have an expected answer,
+/// or `None` if the day isn't registered in this build.
+pub fn check(fixture: &Fixture) -> Result<Option<Vec<Check>>, AocError> {
+    let Some(day) = days::find(fixture.day) else {
+        return Ok(None);
+    };
+    let parts: Vec<_> = fixture.answers.iter().map(|(part, _)| *part).collect();
+
+    let run = (day.run)(&fixture.input, &parts)?;
+    Ok(Some(
+        fixture
+            .answers
+            .iter()
+            .zip(run.parts)
+            .map(|((part, expected), part_run)| Check {
+                part: *part,
+                expected: expected.clone(),
+                status: match answers::matches(expected, &part_run.answer) {
+                    true => Status::Pass,
+                    false => Status::Fail,
+                },
+                actual: part_run.answer,
+            })
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    /// Check every day against the examples that are tracked under `fixtures/examples/`,
+    /// along with the ones that were saved to `data/examples/`.
+    #[test]
+    fn saved_examples() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let tracked = load(&root.join("fixtures/examples")).unwrap();
+        assert!(!tracked.is_empty(), "there are no tracked fixtures");
+        let saved = load(&default_examples_dir(&root.join("data"))).unwrap();
+        let fixtures: Vec<_> = tracked.into_iter().chain(saved).collect();
+
+        let failures: Vec<_> = fixtures
+            .iter()
+            .flat_map(|fixture| match check(fixture) {
+                Ok(checks) => checks
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|check| check.status != Status::Pass)
+                    .map(|check| {
+                        format!(
+                            "day {:02} example {} part {}: expected {}, got {}",
+                            fixture.day, fixture.index, check.part, check.expected, check.actual
+                        )
+                    })
+                    .collect(),
+                Err(err) => vec![format!(
+                    "day {:02} example {}: {err}",
+                    fixture.day, fixture.index
+                )],
+            })
+            .collect();
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn save_and_load() {
+        let dir = TempDir::new("examples_save_and_load");
+
+        let examples = vec![
+            Example {
+                input: "Time:      7  15   30\nDistance:  9  40  200\n".to_string(),
+                answers: vec![(Part::One, Answer::Integer(288))],
+            },
+            Example {
+                input: "Time:      7\nDistance:  9\n".to_string(),
+                answers: vec![],
+            },
+        ];
+        assert_eq!(save(dir.path(), 6, &examples, false).unwrap().len(), 2);
+        // Already there, so nothing gets overwritten.
+        assert_eq!(save(dir.path(), 6, &examples, false).unwrap().len(), 0);
+
+        let fixtures = load(dir.path()).unwrap();
+        assert_eq!(fixtures.len(), 2);
+        assert_eq!(fixtures[0].index, 1);
+        assert_eq!(fixtures[0].answers, examples[0].answers);
+
+        #[cfg(feature = "day-06")]
+        assert_eq!(
+            check(&fixtures[0]).unwrap().unwrap()[0].status,
+            Status::Pass
+        );
+    }
+}
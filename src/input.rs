@@ -0,0 +1,100 @@
+//! Loading puzzle inputs at runtime, so that the solutions can be run
+//! against any input without having to recompile.
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("no input for day {day:02} at {}; download it with `get-inputs` or pass one with `--input`", path.to_string_lossy())]
+    Missing { day: u32, path: PathBuf },
+    #[error("couldn't read the input for day {day:02} from {}: {source}", path.to_string_lossy())]
+    Read {
+        day: u32,
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("couldn't read the input from stdin: {0}")]
+    Stdin(std::io::Error),
+}
+
+/// Where to read a day's puzzle input from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// `<data_dir>/<day>.in`, as written by `get-inputs`.
+    #[default]
+    DataDir,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl FromStr for InputSource {
+    type Err = std::convert::Infallible;
+    /// A `-` stands for stdin, anything else is a path to the input file.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::Path(path.into())),
+        }
+    }
+}
+
+/// The `data/` directory of this crate, falling back to `./data/` when not run through cargo.
+pub fn default_data_dir() -> PathBuf {
+    Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap_or(".".to_string())).join("data/")
+}
+
+pub fn input_path(data_dir: &Path, day: u32) -> PathBuf {
+    data_dir.join(format!("{:02}.in", day))
+}
+
+/// Read the puzzle input for the given day from the given source.
+pub fn load(day: u32, source: &InputSource, data_dir: &Path) -> Result<String, InputError> {
+    let path = match source {
+        InputSource::Stdin => {
+            let mut data = String::new();
+            std::io::stdin()
+                .read_to_string(&mut data)
+                .map_err(InputError::Stdin)?;
+            return Ok(data);
+        }
+        InputSource::Path(path) => path.clone(),
+        InputSource::DataDir => input_path(data_dir, day),
+    };
+
+    std::fs::read_to_string(&path).map_err(|source| match source.kind() {
+        std::io::ErrorKind::NotFound => InputError::Missing { day, path },
+        _ => InputError::Read { day, path, source },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn source_from_str() {
+        assert_eq!("-".parse::<InputSource>().unwrap(), InputSource::Stdin);
+        assert_eq!(
+            "inputs/05.txt".parse::<InputSource>().unwrap(),
+            InputSource::Path("inputs/05.txt".into())
+        );
+    }
+
+    #[test]
+    fn load_from_data_dir() {
+        let data_dir = TempDir::new("load_from_data_dir");
+        std::fs::write(input_path(data_dir.path(), 7), "32T3K 765\n").unwrap();
+
+        assert_eq!(
+            load(7, &InputSource::DataDir, data_dir.path()).unwrap(),
+            "32T3K 765\n"
+        );
+        assert!(matches!(
+            load(8, &InputSource::DataDir, data_dir.path()),
+            Err(InputError::Missing { day: 8, .. })
+        ));
+    }
+}
@@ -0,0 +1,240 @@
+//! Making sense of the JSON of a private leaderboard: when everyone got
+//! their stars, how they ranked on every day and where their points came from.
+use crate::error::AocError;
+use crate::solution::Part;
+use crate::unlock;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum LeaderboardError {
+    #[error("the leaderboard is malformed: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("the event {0:?} isn't a year")]
+    BadEvent(String),
+    #[error(transparent)]
+    Request(#[from] AocError),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    /// Seconds since the Unix epoch.
+    pub get_star_ts: i64,
+    /// Orders the stars that were collected in the same second.
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Missing for users that haven't set up a public name.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    /// Keyed by day and then by part.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u32, BTreeMap<u32, Star>>,
+}
+
+impl Member {
+    /// The name as shown on the site.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    pub fn star(&self, day: u32, part: Part) -> Option<&Star> {
+        self.completion_day_level.get(&day)?.get(&part.number())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    pub members: BTreeMap<u64, Member>,
+}
+
+/// Where a member placed for a single part of a day, and what it earned them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranking {
+    /// Starts at 1.
+    pub rank: usize,
+    pub member: u64,
+    /// How long after the puzzle unlocked the star was collected.
+    pub elapsed: Duration,
+    pub points: u64,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, LeaderboardError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn year(&self) -> Result<u32, LeaderboardError> {
+        self.event
+            .parse()
+            .map_err(|_| LeaderboardError::BadEvent(self.event.clone()))
+    }
+
+    /// The days for which anyone has collected a star.
+    pub fn days(&self) -> Vec<u32> {
+        let mut days: Vec<u32> = self
+            .members
+            .values()
+            .flat_map(|member| member.completion_day_level.keys().copied())
+            .collect();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+
+    /// Everyone who got the star for the given part of a day, fastest first.
+    /// The first one gets as many points as there are members, the next one a point less, and so on.
+    pub fn rankings(&self, day: u32, part: Part) -> Result<Vec<Ranking>, LeaderboardError> {
+        let unlocked_at = unlock::unlocks_at(self.year()?, day).timestamp();
+
+        let mut stars: Vec<_> = self
+            .members
+            .values()
+            .filter_map(|member| Some((member.id, member.star(day, part)?)))
+            .collect();
+        stars.sort_by_key(|(_, star)| (star.get_star_ts, star.star_index));
+
+        Ok(stars
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (member, star))| Ranking {
+                rank: idx + 1,
+                member,
+                elapsed: Duration::from_secs((star.get_star_ts - unlocked_at).max(0) as u64),
+                points: (self.members.len() - idx) as u64,
+            })
+            .collect())
+    }
+
+    /// The points every member earned on each day, which add up to their local score.
+    pub fn score_deltas(&self) -> Result<BTreeMap<u64, BTreeMap<u32, u64>>, LeaderboardError> {
+        let mut deltas: BTreeMap<u64, BTreeMap<u32, u64>> = self
+            .members
+            .keys()
+            .map(|&member| (member, BTreeMap::new()))
+            .collect();
+
+        for day in self.days() {
+            for part in Part::both() {
+                for ranking in self.rankings(day, part)? {
+                    *deltas
+                        .entry(ranking.member)
+                        .or_default()
+                        .entry(day)
+                        .or_default() += ranking.points;
+                }
+            }
+        }
+        Ok(deltas)
+    }
+
+    /// The members ordered by their local score, best first.
+    pub fn standings(&self) -> Vec<&Member> {
+        let mut members: Vec<_> = self.members.values().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.id.cmp(&b.id))
+        });
+        members
+    }
+}
+
+/// Fetch a private leaderboard as the logged in user, who has to be a member of it.
+#[cfg(feature = "leaderboard")]
+pub fn fetch(
+    config: &crate::client::ClientConfig,
+    id: u64,
+) -> Result<Leaderboard, LeaderboardError> {
+    let client = reqwest::blocking::Client::builder()
+        .user_agent(&config.user_agent)
+        .build()
+        .map_err(AocError::from)?;
+
+    let res = client
+        .get(config.leaderboard_url(id))
+        .header("Cookie", config.cookie().map_err(AocError::Http)?)
+        .send()
+        .map_err(AocError::from)?;
+
+    if res.status() != 200 {
+        return Err(AocError::Http(format!(
+            "could not get the leaderboard {id}: the server responded with {}",
+            res.status()
+        ))
+        .into());
+    }
+    let json = res.text().map_err(AocError::from)?;
+    // The server redirects to a regular page instead of failing when we can't see the leaderboard.
+    if json.trim_start().starts_with('<') {
+        return Err(AocError::Http(format!(
+            "could not get the leaderboard {id}: the session isn't logged in or isn't a member of it"
+        ))
+        .into());
+    }
+    Leaderboard::parse(&json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaderboard() -> Leaderboard {
+        Leaderboard::parse(include_str!("../fixtures/leaderboards/2023.json")).unwrap()
+    }
+
+    #[test]
+    fn rankings() {
+        let leaderboard = leaderboard();
+        assert_eq!(leaderboard.days(), vec![1, 2]);
+
+        let rankings = leaderboard.rankings(1, Part::One).unwrap();
+        assert_eq!(
+            rankings
+                .iter()
+                .map(|ranking| (ranking.member, ranking.elapsed.as_secs(), ranking.points))
+                .collect::<Vec<_>>(),
+            vec![(2, 300, 3), (1, 600, 2), (3, 7200, 1)]
+        );
+        assert_eq!(leaderboard.rankings(2, Part::Two).unwrap().len(), 1);
+        assert_eq!(
+            leaderboard.members[&3].display_name(),
+            "(anonymous user #3)"
+        );
+    }
+
+    #[test]
+    fn scores() {
+        let leaderboard = leaderboard();
+        let deltas = leaderboard.score_deltas().unwrap();
+        assert_eq!(deltas[&1], BTreeMap::from([(1, 5), (2, 5)]));
+        assert_eq!(deltas[&2], BTreeMap::from([(1, 5), (2, 3)]));
+        assert_eq!(deltas[&3], BTreeMap::from([(1, 1)]));
+
+        // The deltas add up to the local scores the server reports.
+        for member in leaderboard.members.values() {
+            assert_eq!(deltas[&member.id].values().sum::<u64>(), member.local_score);
+        }
+        assert_eq!(
+            leaderboard
+                .standings()
+                .iter()
+                .map(|member| member.id)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+    }
+}
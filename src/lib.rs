@@ -1,6 +1,41 @@
+pub mod answers;
+
+pub mod bench;
+
+#[cfg(any(feature = "inputs", feature = "submit", feature = "leaderboard"))]
+pub mod client;
+
 pub mod math;
 
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod testing;
+
 #[cfg(feature = "submit")]
 pub mod submit;
 
 pub mod data_structures;
+
+pub mod days;
+
+pub mod diagnostics;
+
+pub mod error;
+
+pub mod examples;
+
+pub mod input;
+
+pub mod leaderboard;
+
+pub mod puzzle;
+
+pub mod report;
+
+pub mod scaffold;
+
+pub mod solution;
+
+pub mod unlock;
@@ -0,0 +1,194 @@
+//! Property tests that pit the clever shortcuts some of the days take against
+//! naive references, which are too slow for the puzzle inputs but easy to trust,
+//! on small random inputs.
+use proptest::prelude::*;
+use std::collections::{BTreeSet, HashSet};
+
+/// The lengths of the runs of broken springs.
+fn damaged_groups(springs: &[u8]) -> Vec<usize> {
+    springs
+        .split(|&spring| spring != b'#')
+        .map(|run| run.len())
+        .filter(|&len| len > 0)
+        .collect()
+}
+
+/// Try every way of filling in the unknown springs.
+fn naive_arrangements(springs: &[u8], groups: &[usize]) -> usize {
+    let unknown: Vec<_> = (0..springs.len())
+        .filter(|&idx| springs[idx] == b'?')
+        .collect();
+
+    (0..1usize << unknown.len())
+        .filter(|choice| {
+            let mut filled = springs.to_vec();
+            for (bit, &idx) in unknown.iter().enumerate() {
+                filled[idx] = if choice & (1 << bit) != 0 { b'#' } else { b'.' };
+            }
+            damaged_groups(&filled) == groups
+        })
+        .count()
+}
+
+fn springs() -> impl Strategy<Value = (Vec<u8>, Vec<usize>)> {
+    (
+        prop::collection::vec(prop::sample::select(b".#?".to_vec()), 0..14),
+        prop::collection::vec(1..5usize, 0..5),
+    )
+}
+
+/// One category of mappings, written the way the almanac lists them.
+/// The source ranges never overlap, as in the puzzle.
+fn category() -> impl Strategy<Value = Vec<(usize, usize, usize)>> {
+    prop::collection::vec((0..8usize, 1..12usize, 0..60usize), 1..4).prop_map(|mappings| {
+        let mut source_start = 0;
+        mappings
+            .into_iter()
+            .map(|(gap, range, destination_start)| {
+                source_start += gap;
+                let mapping = (destination_start, source_start, range);
+                source_start += range;
+                mapping
+            })
+            .collect()
+    })
+}
+
+fn almanac_input(seeds: (usize, usize), categories: &[Vec<(usize, usize, usize)>]) -> String {
+    let mut input = format!("seeds: {} {}", seeds.0, seeds.1);
+    for (idx, category) in categories.iter().enumerate() {
+        input += &format!("\n\ncategory-{idx}-to-category-{} map:", idx + 1);
+        for (destination_start, source_start, range) in category {
+            input += &format!("\n{destination_start} {source_start} {range}");
+        }
+    }
+    input
+}
+
+/// Every point of the polygon, by filling in the columns it's made of.
+fn naive_lattice_points(columns: &[(isize, isize)]) -> usize {
+    let mut points = HashSet::new();
+    for (x, &(bottom, top)) in columns.iter().enumerate() {
+        for y in bottom..=top {
+            points.insert((x as isize, y));
+            points.insert((x as isize + 1, y));
+        }
+    }
+    points.len()
+}
+
+/// Trace the outline of unit wide columns standing side by side, each spanning `bottom..=top`:
+/// along the tops from left to right, and back along the bottoms.
+fn outline(columns: &[(isize, isize)]) -> Vec<(isize, isize)> {
+    let last = columns.len() as isize;
+    let mut outline = vec![];
+    for (x, &(_, top)) in columns.iter().enumerate() {
+        outline.push((x as isize, top));
+        outline.push((x as isize + 1, top));
+    }
+    outline.push((last, columns[columns.len() - 1].0));
+    for (x, &(bottom, _)) in columns.iter().enumerate().rev() {
+        outline.push((x as isize + 1, bottom));
+        outline.push((x as isize, bottom));
+    }
+    outline.dedup();
+    outline
+}
+
+/// Columns where neighbors overlap by at least a unit, so that the outline doesn't touch itself.
+fn columns() -> impl Strategy<Value = Vec<(isize, isize)>> {
+    prop::collection::vec((-6..6isize, 1..8isize), 1..8).prop_map(|columns| {
+        let mut previous: Option<(isize, isize)> = None;
+        columns
+            .into_iter()
+            .map(|(bottom, height)| {
+                let column = match previous {
+                    Some((previous_bottom, previous_top)) => {
+                        let bottom = bottom.clamp(previous_bottom - 6, previous_top - 1);
+                        let top = (bottom + height).max(previous_bottom + 1);
+                        (bottom, top)
+                    }
+                    None => (bottom, bottom + height),
+                };
+                previous = Some(column);
+                column
+            })
+            .collect()
+    })
+}
+
+proptest! {
+    #[test]
+    #[cfg(feature = "day-12")]
+    fn arrangements((springs, groups) in springs()) {
+        use crate::days::day12::Arrangement;
+
+        let arrangement = Arrangement { seq: springs.clone(), groups: groups.clone() };
+        prop_assert_eq!(
+            arrangement.count_by_skipping_contiguous_blocks(),
+            naive_arrangements(&springs, &groups)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "day-05")]
+    fn seed_ranges(
+        seeds in (0..70usize, 1..30usize),
+        categories in prop::collection::vec(category(), 1..5),
+    ) {
+        use crate::days::day05::parse_seeds_and_almanac;
+
+        let (_, almanac) = parse_seeds_and_almanac(&almanac_input(seeds, &categories)).unwrap();
+
+        let locations: BTreeSet<_> = almanac
+            .propagate_seed_range(seeds.0 as isize..(seeds.0 + seeds.1) as isize)
+            .into_iter()
+            .flatten()
+            .map(|location| location as usize)
+            .collect();
+        let expected: BTreeSet<_> = (seeds.0..seeds.0 + seeds.1)
+            .map(|seed| almanac.propagate_seed(seed))
+            .collect();
+        prop_assert_eq!(locations, expected);
+    }
+
+    #[test]
+    fn lattice_points(columns in columns()) {
+        use crate::data_structures::LateralPolygon;
+
+        let polygon = LateralPolygon::new(outline(&columns).into_iter());
+        prop_assert_eq!(polygon.lattice_points(), naive_lattice_points(&columns));
+
+        // Closing the loop explicitly doesn't change a thing.
+        let mut closed = outline(&columns);
+        closed.push(closed[0]);
+        prop_assert_eq!(LateralPolygon::new(closed.into_iter()), polygon);
+    }
+
+    #[test]
+    #[cfg(feature = "day-06")]
+    fn wins(max_time in 0..200u64, min_distance in 0..12_000u64) {
+        use crate::days::day06::count_wins;
+
+        let naive = (0..=max_time)
+            .filter(|&hold| (max_time - hold) * hold > min_distance)
+            .count();
+        prop_assert_eq!(count_wins(max_time, min_distance), naive);
+    }
+
+    #[test]
+    #[cfg(feature = "day-06")]
+    fn wins_near_the_record(max_time in 0..3_000_000_000u64, below in 0..3u64) {
+        use crate::days::day06::count_wins;
+
+        // Right around the best distance, where the bounds are closest to integers.
+        let best = (max_time / 2) * (max_time - max_time / 2);
+        let min_distance = best.saturating_sub(below);
+        let around_the_peak = (max_time / 2).saturating_sub(2)..=max_time / 2 + 2;
+        let naive = around_the_peak
+            .filter(|&hold| hold <= max_time)
+            .filter(|&hold| (max_time - hold) as u128 * hold as u128 > min_distance as u128)
+            .count();
+        prop_assert_eq!(count_wins(max_time, min_distance), naive);
+    }
+}
@@ -0,0 +1,571 @@
+//! Making sense of the HTML of a puzzle page.
+//!
+//! The pages are small and well-formed, so a tiny tokenizer goes a long way
+//! and saves us from pulling in a full HTML parser.
+use crate::solution::{Answer, Part};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<'a> {
+    Open {
+        name: &'a str,
+        attributes: &'a str,
+        self_closing: bool,
+    },
+    Close(&'a str),
+    Text(&'a str),
+}
+
+/// Find the `>` that closes the tag starting at the beginning of `s`,
+/// ignoring any inside quoted attribute values.
+fn tag_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (idx, c) in s.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return Some(idx),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split the HTML into tags and the text between them. Comments, doctypes
+/// and the contents of `<script>` and `<style>` are dropped.
+pub fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment
+                .find("-->")
+                .map_or("", |end| &comment[end + "-->".len()..]);
+            continue;
+        }
+
+        if rest.starts_with('<') {
+            if let Some(end) = tag_end(rest) {
+                let tag = &rest[1..end];
+                rest = &rest[end + 1..];
+
+                if let Some(name) = tag.strip_prefix('/') {
+                    tokens.push(Token::Close(name.trim()));
+                } else if !tag.starts_with('!') && !tag.starts_with('?') {
+                    let self_closing = tag.ends_with('/');
+                    let tag = tag.trim_end_matches('/').trim();
+                    let (name, attributes) =
+                        tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+
+                    if name == "script" || name == "style" {
+                        let closing = format!("</{name}>");
+                        rest = rest.find(&closing).map_or("", |end| &rest[end..]);
+                    }
+                    tokens.push(Token::Open {
+                        name,
+                        attributes: attributes.trim(),
+                        self_closing,
+                    });
+                }
+                continue;
+            }
+        }
+
+        let skip = rest.chars().next().map_or(1, char::len_utf8);
+        let end = rest[skip..].find('<').map_or(rest.len(), |end| end + skip);
+        tokens.push(Token::Text(&rest[..end]));
+        rest = &rest[end..];
+    }
+
+    tokens
+}
+
+/// Replace the character references that show up in puzzle pages.
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = rest.find(';').filter(|&end| end <= 10) else {
+            decoded.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let replacement = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match replacement {
+            Some(c) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// The tokens inside every `<article class="day-desc">`, i.e. one per part of the puzzle that's unlocked.
+pub fn articles<'a>(tokens: &[Token<'a>]) -> Vec<Vec<Token<'a>>> {
+    let mut articles = vec![];
+    let mut current: Option<Vec<Token<'a>>> = None;
+
+    for token in tokens {
+        match (token, current.as_mut()) {
+            (
+                Token::Open {
+                    name: "article",
+                    attributes,
+                    ..
+                },
+                None,
+            ) if attributes.contains("day-desc") => current = Some(vec![]),
+            (Token::Close("article"), Some(_)) => articles.push(current.take().unwrap()),
+            (token, Some(article)) => article.push(*token),
+            _ => {}
+        }
+    }
+
+    articles
+}
+
+/// An example from the puzzle description, along with the answers it's supposed to produce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answers: Vec<(Part, Answer)>,
+}
+
+/// The `<pre><code>` blocks of an article and the last highlighted
+/// `<code><em>` outside of them, which is usually the answer for the example.
+fn blocks_and_answer(article: &[Token]) -> (Vec<String>, Option<String>) {
+    let mut blocks = vec![];
+    let mut answer = None;
+
+    let (mut in_pre, mut in_code, mut in_em) = (false, false, false);
+    let mut text = String::new();
+
+    for token in article {
+        match *token {
+            Token::Open { name, .. } => {
+                match name {
+                    "pre" => in_pre = true,
+                    "code" => in_code = true,
+                    "em" => in_em = true,
+                    _ => continue,
+                }
+                if name == "pre" || (!in_pre && in_code && in_em) {
+                    text.clear();
+                }
+            }
+            Token::Close(name) => {
+                if name == "pre" {
+                    blocks.push(decode_entities(&text));
+                    text.clear();
+                } else if !in_pre && in_code && in_em && (name == "em" || name == "code") {
+                    answer = Some(decode_entities(text.trim()));
+                    text.clear();
+                }
+                match name {
+                    "pre" => in_pre = false,
+                    "code" => in_code = false,
+                    "em" => in_em = false,
+                    _ => {}
+                }
+            }
+            Token::Text(content) => {
+                if in_pre || (in_code && in_em) {
+                    text.push_str(content);
+                }
+            }
+        }
+    }
+
+    (blocks, answer)
+}
+
+/// Pull the examples out of the puzzle page.
+///
+/// The first `<pre><code>` block of each part is taken as the example input, and the
+/// last highlighted answer of that part as its expected answer. When the second part
+/// doesn't come with an example of its own, its answer goes with the first part's example.
+pub fn extract_examples(html: &str) -> Vec<Example> {
+    let tokens = tokenize(html);
+    let mut examples: Vec<Example> = vec![];
+
+    for (article, part) in articles(&tokens).iter().zip(Part::both()) {
+        let (blocks, answer) = blocks_and_answer(article);
+
+        let target = match blocks.into_iter().next() {
+            Some(input) => {
+                examples.push(Example {
+                    input,
+                    answers: vec![],
+                });
+                examples.len() - 1
+            }
+            None if !examples.is_empty() => 0,
+            None => continue,
+        };
+
+        if let Some(answer) = answer {
+            let answer = match answer.parse::<i128>() {
+                Ok(value) => Answer::Integer(value),
+                Err(_) => Answer::Text(answer),
+            };
+            examples[target].answers.push((part, answer));
+        }
+    }
+
+    examples
+}
+
+/// Builds up the Markdown for the blocks of an article, one paragraph, heading or list item at a time.
+#[derive(Debug, Default)]
+struct MarkdownWriter {
+    /// The finished blocks, and whether each is a list item (which aren't separated by blank lines).
+    blocks: Vec<(String, bool)>,
+    /// What goes in front of the current block, e.g. `## ` or `- `.
+    prefix: String,
+    inline: String,
+    /// Whether each open list is ordered, with the number of its next item if so.
+    lists: Vec<Option<usize>>,
+    pre: Option<String>,
+    in_code: bool,
+    links: Vec<String>,
+}
+
+impl MarkdownWriter {
+    fn flush(&mut self) {
+        let text = self.inline.trim();
+        if !text.is_empty() {
+            let is_item = self
+                .prefix
+                .trim_start()
+                .starts_with(|c: char| c == '-' || c.is_ascii_digit());
+            self.blocks
+                .push((format!("{}{}", self.prefix, text), is_item));
+        }
+        self.inline.clear();
+        self.prefix.clear();
+    }
+
+    fn push_text(&mut self, text: &str) {
+        let text = decode_entities(text);
+        if let Some(pre) = self.pre.as_mut() {
+            pre.push_str(&text);
+            return;
+        }
+
+        for (idx, word) in text.split_whitespace().enumerate() {
+            let space_before = idx > 0 || text.starts_with(char::is_whitespace);
+            if space_before && !self.inline.is_empty() && !self.inline.ends_with(' ') {
+                self.inline.push(' ');
+            }
+            match self.in_code {
+                true => self.inline.push_str(word),
+                false => {
+                    for c in word.chars() {
+                        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']') {
+                            self.inline.push('\\');
+                        }
+                        self.inline.push(c);
+                    }
+                }
+            }
+        }
+        if text.ends_with(char::is_whitespace) && !self.inline.is_empty() {
+            self.inline.push(' ');
+        }
+    }
+
+    fn open(&mut self, name: &str, attributes: &str, base_url: &str) {
+        if self.pre.is_some() {
+            return;
+        }
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.flush();
+                let level = name[1..].parse().unwrap_or(2);
+                self.prefix = format!("{} ", "#".repeat(level));
+            }
+            "p" => self.flush(),
+            "ul" => {
+                self.flush();
+                self.lists.push(None);
+            }
+            "ol" => {
+                self.flush();
+                self.lists.push(Some(1));
+            }
+            "li" => {
+                self.flush();
+                let indent = "   ".repeat(self.lists.len().saturating_sub(1));
+                self.prefix = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{indent}{}. ", *number - 1)
+                    }
+                    _ => format!("{indent}- "),
+                };
+            }
+            "pre" => {
+                self.flush();
+                self.pre = Some(String::new());
+            }
+            "code" => {
+                self.in_code = true;
+                self.inline.push('`');
+            }
+            "em" if !self.in_code => self.inline.push('*'),
+            "a" => {
+                let href = attribute(attributes, "href").unwrap_or_default();
+                let href = match href.starts_with('/') {
+                    true => format!("{}{}", base_url.trim_end_matches('/'), href),
+                    false => href,
+                };
+                self.links.push(href);
+                self.inline.push('[');
+            }
+            "br" => self.inline.push_str("  \n"),
+            _ => {}
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        if let Some(pre) = self.pre.as_ref().filter(|_| name == "pre") {
+            let mut block = format!("```\n{pre}");
+            if !block.ends_with('\n') {
+                block.push('\n');
+            }
+            block.push_str("```");
+            self.blocks.push((block, false));
+            self.pre = None;
+            return;
+        }
+        if self.pre.is_some() {
+            return;
+        }
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "p" | "li" => self.flush(),
+            "ul" | "ol" => {
+                self.flush();
+                self.lists.pop();
+            }
+            "code" => {
+                // Trailing spaces would end up inside the backticks.
+                let trimmed = self.inline.trim_end().len();
+                self.inline.truncate(trimmed);
+                self.inline.push('`');
+                self.in_code = false;
+            }
+            "em" if !self.in_code => self.inline.push('*'),
+            "a" => {
+                let href = self.links.pop().unwrap_or_default();
+                self.inline.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.flush();
+        let mut markdown = String::new();
+        let mut previous_item = false;
+        for (block, is_item) in self.blocks {
+            if !markdown.is_empty() {
+                markdown.push_str(match previous_item && is_item {
+                    true => "\n",
+                    false => "\n\n",
+                });
+            }
+            markdown.push_str(&block);
+            previous_item = is_item;
+        }
+        markdown
+    }
+}
+
+/// The value of the attribute with the given name, e.g. the `href` of a link.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let start = attributes.find(&format!("{name}="))? + name.len() + 1;
+    let value = &attributes[start..];
+    let value = match value.chars().next() {
+        Some(quote @ ('"' | '\'')) => value[1..].split(quote).next()?,
+        _ => value.split_whitespace().next()?,
+    };
+    Some(decode_entities(value))
+}
+
+/// Render the parts of the puzzle in the page (its `<article>`s) as Markdown,
+/// so that it can be read offline. Links to other pages of the site are made
+/// absolute with the given base URL.
+pub fn to_markdown(html: &str, base_url: &str) -> String {
+    let tokens = tokenize(html);
+    let parts: Vec<_> = articles(&tokens)
+        .iter()
+        .map(|article| {
+            let mut writer = MarkdownWriter::default();
+            for token in article {
+                match *token {
+                    Token::Open {
+                        name, attributes, ..
+                    } => writer.open(name, attributes, base_url),
+                    Token::Close(name) => writer.close(name),
+                    Token::Text(text) => writer.push_text(text),
+                }
+            }
+            writer.finish()
+        })
+        .collect();
+
+    let mut markdown = parts.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+/// Whether the Markdown of a puzzle already has its second part, which is only
+/// on the page once the first part has been solved.
+pub fn has_both_parts(markdown: &str) -> bool {
+    markdown.contains("--- Part Two ---")
+}
+
+pub fn default_puzzles_dir(data_dir: &Path) -> PathBuf {
+    data_dir.join("puzzles")
+}
+
+pub fn markdown_path(dir: &Path, day: u32) -> PathBuf {
+    dir.join(format!("{:02}.md", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens() {
+        let tokens = tokenize(
+            r#"<!DOCTYPE html><p title="a > b">Hi &amp; bye<br/></p><!-- <em>not</em> --><script>if (a < b) {}</script>"#,
+        );
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Open {
+                    name: "p",
+                    attributes: r#"title="a > b""#,
+                    self_closing: false
+                },
+                Token::Text("Hi &amp; bye"),
+                Token::Open {
+                    name: "br",
+                    attributes: "",
+                    self_closing: true
+                },
+                Token::Close("p"),
+                Token::Open {
+                    name: "script",
+                    attributes: "",
+                    self_closing: false
+                },
+                Token::Close("script"),
+            ]
+        );
+    }
+
+    #[test]
+    fn non_ascii_tokens() {
+        assert_eq!(
+            tokenize("<p>é<code>ü</code>ß</p>"),
+            vec![
+                Token::Open {
+                    name: "p",
+                    attributes: "",
+                    self_closing: false
+                },
+                Token::Text("é"),
+                Token::Open {
+                    name: "code",
+                    attributes: "",
+                    self_closing: false
+                },
+                Token::Text("ü"),
+                Token::Close("code"),
+                Token::Text("ß"),
+                Token::Close("p"),
+            ]
+        );
+        assert_eq!(
+            to_markdown(
+                r#"<article class="day-desc"><p>é<code><em>5</em></code></p></article>"#,
+                ""
+            ),
+            "é`5`\n"
+        );
+    }
+
+    #[test]
+    fn entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp; &#39;c&#x27; & d;"),
+            "a <b> & 'c' & d;"
+        );
+    }
+
+    #[test]
+    fn examples() {
+        let html = include_str!("../fixtures/puzzles/05.html");
+        let examples = extract_examples(html);
+
+        assert_eq!(examples.len(), 1);
+        assert_eq!(
+            examples[0].input,
+            include_str!("../fixtures/examples/05-1.in")
+        );
+        assert_eq!(
+            examples[0].answers,
+            vec![
+                (Part::One, Answer::Integer(35)),
+                (Part::Two, Answer::Integer(46))
+            ]
+        );
+    }
+
+    #[test]
+    fn markdown() {
+        let html = include_str!("../fixtures/puzzles/05.html");
+        assert_eq!(
+            to_markdown(html, "https://adventofcode.com"),
+            include_str!("../fixtures/puzzles/05.md")
+        );
+        assert!(has_both_parts(&to_markdown(html, "")));
+    }
+
+    #[test]
+    fn markdown_lists() {
+        let html = r#"<article class="day-desc"><ol><li>One <code>a * b</code></li><li>Two<ul><li>nested_item</li></ul></li></ol><p>x<br/>y</p></article>"#;
+        assert_eq!(
+            to_markdown(html, ""),
+            "1. One `a * b`\n2. Two\n   - nested\\_item\n\nx  \ny\n"
+        );
+    }
+}
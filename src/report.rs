@@ -0,0 +1,161 @@
+//! The outcome of solving days in a form that other programs can read,
+//! e.g. to put it on a dashboard or to pipe the answers into `submit`:
+//! one record per part, with its status, answer and timings.
+use crate::solution::{Answer, Part, Run};
+use serde::Serialize;
+use std::str::FromStr;
+
+/// How `run` and `bench` print their results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Lines meant for people to read.
+    #[default]
+    Text,
+    /// A single array with all the records.
+    Json,
+    /// Tab separated values with a header, a record per line.
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(format!("expected one of `text`, `json` or `tsv`, got: {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordStatus {
+    Solved,
+    /// The part hasn't been solved (yet).
+    Unsolved,
+    /// The input couldn't be read or parsed, so the part couldn't be solved.
+    Error,
+}
+
+impl std::fmt::Display for RecordStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordStatus::Solved => write!(f, "solved"),
+            RecordStatus::Unsolved => write!(f, "unsolved"),
+            RecordStatus::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    pub status: RecordStatus,
+    /// Only there for the parts that were solved.
+    pub answer: Option<String>,
+    /// Both parts share the parsed input, so they report the same time for parsing it.
+    pub parse_ns: Option<u64>,
+    pub elapsed_ns: Option<u64>,
+}
+
+pub const TSV_HEADER: &str = "day\tpart\tstatus\tanswer\tparse_ns\telapsed_ns";
+
+impl Record {
+    /// The fields in the order of [`TSV_HEADER`], with missing ones left empty.
+    pub fn to_tsv(&self) -> String {
+        let or_empty = |value: Option<String>| value.unwrap_or_default();
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.day,
+            self.part,
+            self.status,
+            or_empty(self.answer.clone()),
+            or_empty(self.parse_ns.map(|ns| ns.to_string())),
+            or_empty(self.elapsed_ns.map(|ns| ns.to_string())),
+        )
+    }
+}
+
+/// A record for every requested part of a day, or records with an error status
+/// for all of them when the day didn't get to run.
+pub fn records(day: u32, parts: &[Part], run: Option<&Run>) -> Vec<Record> {
+    let Some(run) = run else {
+        return parts
+            .iter()
+            .map(|part| Record {
+                day,
+                part: part.number(),
+                status: RecordStatus::Error,
+                answer: None,
+                parse_ns: None,
+                elapsed_ns: None,
+            })
+            .collect();
+    };
+
+    run.parts
+        .iter()
+        .map(|part_run| {
+            let solved = part_run.answer != Answer::Unsolved;
+            Record {
+                day: run.day,
+                part: part_run.part.number(),
+                status: match solved {
+                    true => RecordStatus::Solved,
+                    false => RecordStatus::Unsolved,
+                },
+                answer: solved.then(|| part_run.answer.to_string()),
+                parse_ns: Some(run.parse.as_nanos() as u64),
+                elapsed_ns: solved.then_some(part_run.elapsed.as_nanos() as u64),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::PartRun;
+    use std::time::Duration;
+
+    #[test]
+    fn records_and_tsv() {
+        let run = Run {
+            day: 5,
+            parse: Duration::from_nanos(1500),
+            parts: vec![
+                PartRun {
+                    part: Part::One,
+                    answer: Answer::Integer(35),
+                    elapsed: Duration::from_nanos(200),
+                },
+                PartRun {
+                    part: Part::Two,
+                    answer: Answer::Unsolved,
+                    elapsed: Duration::from_nanos(10),
+                },
+            ],
+        };
+        let records = records(5, &Part::both(), Some(&run));
+        assert_eq!(
+            records.iter().map(Record::to_tsv).collect::<Vec<_>>(),
+            vec!["5\t1\tsolved\t35\t1500\t200", "5\t2\tunsolved\t\t1500\t"]
+        );
+        assert_eq!(
+            serde_json::to_string(&records[0]).unwrap(),
+            r#"{"day":5,"part":1,"status":"solved","answer":"35","parse_ns":1500,"elapsed_ns":200}"#
+        );
+    }
+
+    #[test]
+    fn failed_day() {
+        let records = records(19, &[Part::Two], None);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].to_tsv(), "19\t2\terror\t\t\t");
+        assert_eq!("JSON".parse(), Ok(Format::Json));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
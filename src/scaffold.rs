@@ -0,0 +1,292 @@
+//! Setting up a new day: its module from the template, its `day-NN` feature
+//! and its spot in the runner's registry.
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+
+#[derive(Debug, Error)]
+pub enum ScaffoldError {
+    #[error("{} already exists", .0.to_string_lossy())]
+    AlreadyExists(PathBuf),
+    #[error("day {day} is already registered in {}", path.to_string_lossy())]
+    AlreadyRegistered { day: u32, path: PathBuf },
+    #[error("couldn't find where {what} go in {}", path.to_string_lossy())]
+    Unrecognized { what: &'static str, path: PathBuf },
+    #[error("couldn't access {}: {source}", path.to_string_lossy())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+/// Why an item couldn't be added to a file, before we know which file it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertError {
+    AlreadyThere,
+    NotFound(&'static str),
+}
+
+impl InsertError {
+    fn in_file(self, day: u32, path: &Path) -> ScaffoldError {
+        match self {
+            InsertError::AlreadyThere => ScaffoldError::AlreadyRegistered {
+                day,
+                path: path.to_path_buf(),
+            },
+            InsertError::NotFound(what) => ScaffoldError::Unrecognized {
+                what,
+                path: path.to_path_buf(),
+            },
+        }
+    }
+}
+
+/// The source of a new day's module.
+pub fn render_module(day: u32) -> String {
+    TEMPLATE
+        .replace("{{NN}}", &format!("{:02}", day))
+        .replace("{{DAY}}", &day.to_string())
+}
+
+/// The day number of a `#[cfg(feature = "day-NN")]` line.
+fn gated_day(line: &str) -> Option<u32> {
+    line.trim()
+        .strip_prefix("#[cfg(feature = \"day-")?
+        .strip_suffix("\")]")?
+        .parse()
+        .ok()
+}
+
+/// Add `item` behind the day's feature gate, among the other gated items
+/// that `is_item` recognizes, keeping them ordered by day.
+fn insert_gated(
+    source: &str,
+    day: u32,
+    item: &str,
+    is_item: impl Fn(&str) -> bool,
+) -> Result<String, InsertError> {
+    let lines: Vec<&str> = source.lines().collect();
+    let gated: Vec<(u32, usize)> = lines
+        .windows(2)
+        .enumerate()
+        .filter_map(|(idx, pair)| Some((gated_day(pair[0])?, idx)).filter(|_| is_item(pair[1])))
+        .collect();
+
+    if gated.iter().any(|&(other, _)| other == day) {
+        return Err(InsertError::AlreadyThere);
+    }
+    let Some(&(_, last)) = gated.last() else {
+        return Err(InsertError::NotFound("the days"));
+    };
+    let at = gated
+        .iter()
+        .find(|&&(other, _)| other > day)
+        .map_or(last + 2, |&(_, idx)| idx);
+
+    let indent = &lines[last][..lines[last].len() - lines[last].trim_start().len()];
+    let mut result: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    result.splice(
+        at..at,
+        [
+            format!("{indent}#[cfg(feature = \"day-{:02}\")]", day),
+            format!("{indent}{item}"),
+        ],
+    );
+
+    let mut result = result.join("\n");
+    if source.ends_with('\n') {
+        result.push('\n');
+    }
+    Ok(result)
+}
+
+/// Declare the day's module in `src/days/mod.rs` and add it to the registry.
+pub fn register_module(mod_rs: &str, day: u32) -> Result<String, InsertError> {
+    let module = format!("pub mod day{:02};", day);
+    let registered = insert_gated(mod_rs, day, &module, |line| {
+        line.trim().starts_with("pub mod day")
+    })?;
+
+    let push = format!("days.push(Day::of::<day{day:02}::Day{day:02}>());");
+    insert_gated(&registered, day, &push, |line| {
+        line.trim().starts_with("days.push(")
+    })
+}
+
+/// Add the day's feature to `Cargo.toml`, enabled by default like the others.
+pub fn register_feature(cargo_toml: &str, day: u32) -> Result<String, InsertError> {
+    let feature = format!("day-{:02}", day);
+    let start = cargo_toml
+        .find("\ndefault = [")
+        .ok_or(InsertError::NotFound("the default features"))?
+        + "\ndefault = [".len();
+    let end = start
+        + cargo_toml[start..]
+            .find(']')
+            .ok_or(InsertError::NotFound("the default features"))?;
+
+    let mut defaults: Vec<String> = cargo_toml[start..end]
+        .split(',')
+        .map(|name| name.trim().trim_matches('"').to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+    if defaults.contains(&feature) {
+        return Err(InsertError::AlreadyThere);
+    }
+    defaults.push(feature.clone());
+    defaults.sort();
+
+    let mut rendered = String::from("\n");
+    for chunk in defaults.chunks(8) {
+        let names: Vec<_> = chunk.iter().map(|name| format!("\"{name}\"")).collect();
+        rendered.push_str(&format!("    {},\n", names.join(", ")));
+    }
+
+    let with_default = format!("{}{}{}", &cargo_toml[..start], rendered, &cargo_toml[end..]);
+
+    // The features of the days are declared in order, each on its own line.
+    let declarations: Vec<(u32, usize)> = with_default
+        .match_indices("\nday-")
+        .filter_map(|(idx, _)| {
+            let line = with_default[idx + 1..].lines().next()?;
+            let (name, _) = line.split_once('=')?;
+            Some((name.trim().strip_prefix("day-")?.parse().ok()?, idx + 1))
+        })
+        .collect();
+    let Some(&(_, last)) = declarations.last() else {
+        return Err(InsertError::NotFound("the day features"));
+    };
+    let at = declarations
+        .iter()
+        .find(|&&(other, _)| other > day)
+        .map_or_else(
+            || last + with_default[last..].find('\n').map_or(0, |end| end + 1),
+            |&(_, idx)| idx,
+        );
+
+    Ok(format!(
+        "{}{feature} = []\n{}",
+        &with_default[..at],
+        &with_default[at..]
+    ))
+}
+
+/// The root of this crate when run through cargo, otherwise the current directory.
+pub fn default_root() -> PathBuf {
+    PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap_or(".".to_string()))
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    std::fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    std::fs::write(path, contents).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Set up a new day in the crate at `root`, returning the files that were written.
+/// Nothing is written unless every file could be updated.
+pub fn scaffold(root: &Path, day: u32) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let module_path = root.join(format!("src/days/day{:02}.rs", day));
+    let mod_rs_path = root.join("src/days/mod.rs");
+    let cargo_toml_path = root.join("Cargo.toml");
+
+    if module_path.exists() {
+        return Err(ScaffoldError::AlreadyExists(module_path));
+    }
+    let mod_rs =
+        register_module(&read(&mod_rs_path)?, day).map_err(|err| err.in_file(day, &mod_rs_path))?;
+    let cargo_toml = register_feature(&read(&cargo_toml_path)?, day)
+        .map_err(|err| err.in_file(day, &cargo_toml_path))?;
+
+    write(&module_path, &render_module(day))?;
+    write(&mod_rs_path, &mod_rs)?;
+    write(&cargo_toml_path, &cargo_toml)?;
+    Ok(vec![module_path, mod_rs_path, cargo_toml_path])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module() {
+        let module = render_module(7);
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("const DAY: u32 = 7;"));
+        assert!(!module.contains("{{"));
+    }
+
+    #[test]
+    fn registry() {
+        let mod_rs = r#"#[cfg(feature = "day-01")]
+pub mod day01;
+#[cfg(feature = "day-03")]
+pub mod day03;
+
+pub fn registry() -> Vec<Day> {
+    let mut days = vec![];
+
+    #[cfg(feature = "day-01")]
+    days.push(Day::of::<day01::Day01>());
+    #[cfg(feature = "day-03")]
+    days.push(Day::of::<day03::Day03>());
+
+    days
+}
+"#;
+        let registered = register_module(mod_rs, 2).unwrap();
+        assert!(registered.contains(
+            "pub mod day01;\n#[cfg(feature = \"day-02\")]\npub mod day02;\n#[cfg(feature = \"day-03\")]"
+        ));
+        assert!(registered.contains(
+            "    #[cfg(feature = \"day-02\")]\n    days.push(Day::of::<day02::Day02>());\n    #[cfg(feature = \"day-03\")]"
+        ));
+        assert!(register_module(&register_module(mod_rs, 4).unwrap(), 4).is_err());
+        assert!(register_module(mod_rs, 4)
+            .unwrap()
+            .contains("days.push(Day::of::<day04::Day04>());\n\n    days"));
+    }
+
+    #[test]
+    fn features() {
+        let cargo_toml = r#"[features]
+default = [
+    "day-01", "day-03",
+]
+inputs = ["dep:bytes"]
+day-01 = []
+day-03 = ["dep:rayon"]
+
+[dependencies]
+"#;
+        assert_eq!(
+            register_feature(cargo_toml, 2).unwrap(),
+            r#"[features]
+default = [
+    "day-01", "day-02", "day-03",
+]
+inputs = ["dep:bytes"]
+day-01 = []
+day-02 = []
+day-03 = ["dep:rayon"]
+
+[dependencies]
+"#
+        );
+        assert!(register_feature(cargo_toml, 4)
+            .unwrap()
+            .contains("day-03 = [\"dep:rayon\"]\nday-04 = []\n\n[dependencies]"));
+        assert_eq!(
+            register_feature(cargo_toml, 3),
+            Err(InsertError::AlreadyThere)
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::error::AocError;
+use std::time::{Duration, Instant};
+
+/// The answer to a single part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// The part hasn't been solved (yet).
+    Unsolved,
+}
+
+impl std::fmt::Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn both() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+
+    pub fn number(&self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl TryFrom<u32> for Part {
+    type Error = String;
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("expected part 1 or 2, got: {value}")),
+        }
+    }
+}
+
+/// A solution to a single day's puzzle.
+///
+/// Parsing the input is a separate stage from solving either part so that
+/// the two parts can share the parsed input and be timed on their own.
+pub trait Solution {
+    /// The day of December this puzzle was released on.
+    const DAY: u32;
+
+    /// The structured form of the puzzle input that both parts work with.
+    type Input;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+/// The outcome of solving a single part of a puzzle.
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// The outcome of parsing the input for a day and then solving the requested parts.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub day: u32,
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+/// Parse the given input and solve the requested parts of a puzzle, timing every stage.
+pub fn run<S: Solution>(data: &str, parts: &[Part]) -> Result<Run, AocError> {
+    let start = Instant::now();
+    let input = S::parse(data)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&input),
+                Part::Two => S::part2(&input),
+            };
+            PartRun {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Run {
+        day: S::DAY,
+        parse,
+        parts,
+    })
+}
@@ -0,0 +1,297 @@
+//! A local record of every answer we've submitted, so that we don't
+//! waste a submission (and the wait penalty that comes with a wrong one)
+//! on an answer the server has already told us about.
+use super::{Hint, SubmitOutcome};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum LedgerError {
+    #[error("couldn't access the submission ledger at {}: {source}", path.to_string_lossy())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("the submission ledger at {} is malformed: {source}", path.to_string_lossy())]
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+/// Why an answer wasn't sent to the server.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum Refusal {
+    #[error("this part was already solved with {0}")]
+    AlreadySolved(String),
+    #[error("{0} was already submitted and it's not the right answer")]
+    KnownWrong(String),
+    #[error("{answer} is out of bounds: the answer is {}", describe_bounds(*lower, *upper))]
+    OutOfBounds {
+        answer: i128,
+        /// The answer must be strictly greater than this.
+        lower: Option<i128>,
+        /// The answer must be strictly less than this.
+        upper: Option<i128>,
+    },
+    #[error("the server won't accept another answer for {}s", remaining.as_secs())]
+    Cooldown { remaining: Duration },
+}
+
+fn describe_bounds(lower: Option<i128>, upper: Option<i128>) -> String {
+    match (lower, upper) {
+        (Some(lower), Some(upper)) => format!("between {lower} and {upper}"),
+        (Some(lower), None) => format!("greater than {lower}"),
+        (None, Some(upper)) => format!("less than {upper}"),
+        (None, None) => "unbounded".to_string(),
+    }
+}
+
+/// A single submission and what the server made of it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub outcome: SubmitOutcome,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: u64,
+}
+
+impl Entry {
+    /// When the server will accept the next answer for this part, if it asked us to wait.
+    pub fn ready_at(&self) -> Option<u64> {
+        match self.outcome {
+            SubmitOutcome::Wrong {
+                wait: Some(wait), ..
+            }
+            | SubmitOutcome::TooRecent { wait } => Some(self.submitted_at + wait.as_secs()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ledger {
+    pub entries: Vec<Entry>,
+}
+
+pub fn default_ledger_path(data_dir: &Path) -> PathBuf {
+    data_dir.join("submissions.json")
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+impl Ledger {
+    /// Read the ledger from the given file. A missing file is just an empty ledger.
+    pub fn load(path: &Path) -> Result<Self, LedgerError> {
+        let json = match std::fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(source) => {
+                return Err(LedgerError::Io {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        };
+        serde_json::from_str(&json).map_err(|source| LedgerError::Json {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), LedgerError> {
+        let json = serde_json::to_string_pretty(self).map_err(|source| LedgerError::Json {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        std::fs::write(path, json).map_err(|source| LedgerError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn entries_for(&self, year: u32, day: u32, part: u32) -> impl Iterator<Item = &Entry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.year == year && entry.day == day && entry.part == part)
+    }
+
+    /// The range that the answer must lie in (exclusive on both ends),
+    /// going by the too high/too low hints for earlier submissions.
+    pub fn bounds(&self, year: u32, day: u32, part: u32) -> (Option<i128>, Option<i128>) {
+        let mut lower: Option<i128> = None;
+        let mut upper: Option<i128> = None;
+
+        for entry in self.entries_for(year, day, part) {
+            let SubmitOutcome::Wrong {
+                hint: Some(hint), ..
+            } = entry.outcome
+            else {
+                continue;
+            };
+            let Ok(answer) = entry.answer.trim().parse::<i128>() else {
+                continue;
+            };
+            match hint {
+                Hint::TooLow => lower = Some(lower.map_or(answer, |lower| lower.max(answer))),
+                Hint::TooHigh => upper = Some(upper.map_or(answer, |upper| upper.min(answer))),
+            }
+        }
+        (lower, upper)
+    }
+
+    /// Check whether the answer is worth submitting at the given time (in seconds since the Unix epoch).
+    pub fn check(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), Refusal> {
+        let answer = answer.trim();
+
+        for entry in self.entries_for(year, day, part) {
+            match entry.outcome {
+                SubmitOutcome::Correct => {
+                    return Err(Refusal::AlreadySolved(entry.answer.clone()));
+                }
+                SubmitOutcome::Wrong { .. } if entry.answer.trim() == answer => {
+                    return Err(Refusal::KnownWrong(answer.to_string()));
+                }
+                _ => {}
+            }
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let (lower, upper) = self.bounds(year, day, part);
+            let too_low = lower.is_some_and(|lower| value <= lower);
+            let too_high = upper.is_some_and(|upper| value >= upper);
+            if too_low || too_high {
+                return Err(Refusal::OutOfBounds {
+                    answer: value,
+                    lower,
+                    upper,
+                });
+            }
+        }
+
+        if let Some(ready_at) = self
+            .entries_for(year, day, part)
+            .filter_map(|entry| entry.ready_at())
+            .max()
+        {
+            if now < ready_at {
+                return Err(Refusal::Cooldown {
+                    remaining: Duration::from_secs(ready_at - now),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(
+        &mut self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+        outcome: SubmitOutcome,
+        now: u64,
+    ) {
+        self.entries.push(Entry {
+            year,
+            day,
+            part,
+            answer: answer.trim().to_string(),
+            outcome,
+            submitted_at: now,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    fn wrong(hint: Option<Hint>, wait_secs: u64) -> SubmitOutcome {
+        SubmitOutcome::Wrong {
+            hint,
+            wait: Some(Duration::from_secs(wait_secs)),
+        }
+    }
+
+    #[test]
+    fn refusals() {
+        let mut ledger = Ledger::default();
+        ledger.record(2023, 5, 1, "100", wrong(Some(Hint::TooLow), 60), 1000);
+        ledger.record(2023, 5, 1, "500", wrong(Some(Hint::TooHigh), 300), 1100);
+        ledger.record(2023, 5, 1, "abc", wrong(None, 60), 1200);
+
+        assert_eq!(
+            ledger.check(2023, 5, 1, "abc", 5000),
+            Err(Refusal::KnownWrong("abc".to_string()))
+        );
+        assert_eq!(
+            ledger.check(2023, 5, 1, "50", 5000),
+            Err(Refusal::OutOfBounds {
+                answer: 50,
+                lower: Some(100),
+                upper: Some(500)
+            })
+        );
+        assert!(matches!(
+            ledger.check(2023, 5, 1, "500", 5000),
+            Err(Refusal::KnownWrong(_))
+        ));
+        assert_eq!(
+            ledger.check(2023, 5, 1, "250", 1300),
+            Err(Refusal::Cooldown {
+                remaining: Duration::from_secs(100)
+            })
+        );
+        assert_eq!(ledger.check(2023, 5, 1, "250", 1400), Ok(()));
+        // Other parts and other years aren't affected.
+        assert_eq!(ledger.check(2023, 5, 2, "50", 1300), Ok(()));
+        assert_eq!(ledger.check(2022, 5, 1, "abc", 1300), Ok(()));
+
+        ledger.record(2023, 5, 1, "250", SubmitOutcome::Correct, 1400);
+        assert_eq!(
+            ledger.check(2023, 5, 1, "251", 1500),
+            Err(Refusal::AlreadySolved("250".to_string()))
+        );
+    }
+
+    #[test]
+    fn save_and_load() {
+        let dir = TempDir::new("ledger_save_and_load");
+        let path = dir.path().join("submissions.json");
+        let mut ledger = Ledger::default();
+        ledger.record(2023, 7, 2, "6440", wrong(Some(Hint::TooHigh), 60), 1000);
+        ledger.record(
+            2023,
+            7,
+            2,
+            "5905",
+            SubmitOutcome::TooRecent {
+                wait: Duration::from_secs(30),
+            },
+            1010,
+        );
+
+        ledger.save(&path).unwrap();
+        assert_eq!(Ledger::load(&path).unwrap(), ledger);
+    }
+}
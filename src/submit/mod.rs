@@ -0,0 +1,205 @@
+use crate::client::ClientConfig;
+use crate::error::AocError;
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use thiserror::Error;
+
+pub mod ledger;
+pub use ledger::{Ledger, Refusal};
+
+lazy_static! {
+    static ref TOO_RECENT_RE: Regex =
+        Regex::new(r"You have (?:(?<minutes>\d+)m )?(?<seconds>\d+)s left to wait").unwrap();
+    static ref WRONG_ANSWER_WAIT_RE: Regex =
+        Regex::new(r"(?i)wait (?<minutes>one|\d+) minutes? before trying again").unwrap();
+}
+
+/// Whether the server said a wrong answer was too high or too low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// What the server made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubmitOutcome {
+    Correct,
+    /// The answer is wrong, and the next one can only be submitted after `wait`.
+    Wrong {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    /// The answer wasn't checked because the previous one was submitted too recently.
+    TooRecent {
+        wait: Duration,
+    },
+    /// The answer wasn't checked because it's for a part that isn't unlocked yet,
+    /// or that has already been solved: the server says the same for both.
+    WrongLevel,
+}
+
+impl SubmitOutcome {
+    /// Make sense of the `<article>` in the page the server responds with after a submission.
+    pub fn from_response(html: &str) -> Result<Self, AocError> {
+        if html.contains("That's the right answer") {
+            return Ok(SubmitOutcome::Correct);
+        }
+
+        if html.contains("That's not the right answer") {
+            let hint = if html.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if html.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            let wait = WRONG_ANSWER_WAIT_RE.captures(html).map(|captures| {
+                let minutes = match &captures["minutes"] {
+                    "one" => 1,
+                    minutes => minutes.parse().unwrap(),
+                };
+                Duration::from_secs(minutes * 60)
+            });
+            return Ok(SubmitOutcome::Wrong { hint, wait });
+        }
+
+        if html.contains("You gave an answer too recently") {
+            let Some(captures) = TOO_RECENT_RE.captures(html) else {
+                return Err(AocError::Http(
+                    "couldn't find the time left to wait in the response.".to_string(),
+                ));
+            };
+            let minutes: u64 = captures
+                .name("minutes")
+                .map(|minutes| minutes.as_str().parse().unwrap())
+                .unwrap_or_default();
+            let seconds: u64 = captures["seconds"].parse().unwrap();
+            return Ok(SubmitOutcome::TooRecent {
+                wait: Duration::from_secs(minutes * 60 + seconds),
+            });
+        }
+
+        if html.contains("You don't seem to be solving the right level") {
+            return Ok(SubmitOutcome::WrongLevel);
+        }
+
+        Err(AocError::Http(
+            "couldn't make sense of the response from the server.".to_string(),
+        ))
+    }
+}
+
+pub fn submit(
+    config: &ClientConfig,
+    day: u32,
+    part: u32,
+    solution: &str,
+) -> Result<SubmitOutcome, AocError> {
+    let url = config.answer_url(day);
+    let client = Client::builder().user_agent(&config.user_agent).build()?;
+
+    let form: std::collections::HashMap<String, String> = [
+        (String::from("answer"), solution.to_string()),
+        (String::from("level"), part.to_string()),
+    ]
+    .into_iter()
+    .collect();
+
+    let res = client
+        .post(url)
+        .form(&form)
+        .header("Cookie", config.cookie().map_err(AocError::Http)?)
+        .send()?;
+
+    if res.status() != 200 {
+        return Err(AocError::Http(format!(
+            "the server responded with {}",
+            res.status()
+        )));
+    }
+    SubmitOutcome::from_response(&res.text()?)
+}
+
+#[derive(Debug, Error)]
+pub enum SubmitError {
+    #[error("not submitting: {0}")]
+    Refused(#[from] Refusal),
+    #[error(transparent)]
+    Request(#[from] AocError),
+}
+
+/// Submit the answer unless the ledger already knows it's not worth it,
+/// and record whatever the server made of it.
+pub fn submit_with_ledger(
+    config: &ClientConfig,
+    ledger: &mut Ledger,
+    day: u32,
+    part: u32,
+    solution: &str,
+) -> Result<SubmitOutcome, SubmitError> {
+    ledger.check(config.year, day, part, solution, ledger::now())?;
+    let outcome = submit(config, day, part, solution)?;
+    ledger.record(config.year, day, part, solution, outcome, ledger::now());
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outcomes() {
+        let cases = [
+            (
+                "<article><p>That's the right answer!  You are <em>one gold star</em> closer to restoring snow operations.</p></article>",
+                SubmitOutcome::Correct,
+            ),
+            (
+                "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2023/day/5\">[Return to Day 5]</a></p></article>",
+                SubmitOutcome::Wrong {
+                    hint: Some(Hint::TooHigh),
+                    wait: Some(Duration::from_secs(60)),
+                },
+            ),
+            (
+                "<article><p>That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.</p></article>",
+                SubmitOutcome::Wrong {
+                    hint: Some(Hint::TooLow),
+                    wait: Some(Duration::from_secs(300)),
+                },
+            ),
+            (
+                "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>",
+                SubmitOutcome::Wrong {
+                    hint: None,
+                    wait: None,
+                },
+            ),
+            (
+                "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait. <a href=\"/2023/day/5\">[Return to Day 5]</a></p></article>",
+                SubmitOutcome::TooRecent {
+                    wait: Duration::from_secs(34),
+                },
+            ),
+            (
+                "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 2s left to wait.</p></article>",
+                SubmitOutcome::TooRecent {
+                    wait: Duration::from_secs(242),
+                },
+            ),
+            (
+                "<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/5\">[Return to Day 5]</a></p></article>",
+                SubmitOutcome::WrongLevel,
+            ),
+        ];
+
+        for (html, expected) in cases {
+            assert_eq!(SubmitOutcome::from_response(html).unwrap(), expected);
+        }
+        assert!(SubmitOutcome::from_response("<html></html>").is_err());
+    }
+}
@@ -0,0 +1,26 @@
+//! Helpers for the tests of more than one module.
+use std::path::{Path, PathBuf};
+
+/// A directory of a test's own, removed along with everything in it when dropped.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    /// Named after the test and the process, so that tests running at the same time
+    /// don't step on each other's files.
+    pub fn new(test: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("aoc-2023-{test}-{}", std::process::id()));
+        _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        _ = std::fs::remove_dir_all(&self.0);
+    }
+}
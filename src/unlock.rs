@@ -0,0 +1,83 @@
+//! When the puzzles of an event come out.
+//!
+//! A new puzzle unlocks every day from the 1st of December at midnight
+//! US Eastern time, which is always 05:00 UTC since daylight saving time
+//! isn't in effect in December.
+use chrono::{DateTime, TimeZone, Utc};
+
+/// The events run until the 25th, except from 2025 on, when they were cut down to 12 days.
+pub fn last_day(year: u32) -> u32 {
+    match year {
+        ..=2024 => 25,
+        _ => 12,
+    }
+}
+
+/// Midnight in US Eastern (UTC-5) is 05:00 UTC.
+const UNLOCK_HOUR_UTC: u32 = 5;
+
+/// The moment the puzzle for the given day of the event unlocks.
+pub fn unlocks_at(year: u32, day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year as i32, 12, day, UNLOCK_HOUR_UTC, 0, 0)
+        .unwrap()
+}
+
+/// The days of the event whose puzzles are out at the given moment.
+pub fn released_days(year: u32, now: DateTime<Utc>) -> std::ops::Range<u32> {
+    let released = (1..=last_day(year))
+        .take_while(|&day| unlocks_at(year, day) <= now)
+        .count() as u32;
+    1..released + 1
+}
+
+pub fn is_released(year: u32, day: u32, now: DateTime<Utc>) -> bool {
+    released_days(year, now).contains(&day)
+}
+
+/// The next day to unlock and when, or `None` once the whole event is out.
+pub fn next_unlock(year: u32, now: DateTime<Utc>) -> Option<(u32, DateTime<Utc>)> {
+    (1..=last_day(year))
+        .map(|day| (day, unlocks_at(year, day)))
+        .find(|(_, at)| *at > now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(month: u32, day: u32, hour: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2023, month, day, hour, min, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn released() {
+        assert_eq!(released_days(2023, utc(11, 30, 12, 0)), 1..1);
+        // The evening of the 11th in New York, which is already the 12th in UTC.
+        assert_eq!(released_days(2023, utc(12, 12, 4, 59)), 1..12);
+        assert_eq!(released_days(2023, utc(12, 12, 5, 0)), 1..13);
+        assert_eq!(released_days(2023, utc(12, 31, 0, 0)), 1..26);
+        assert_eq!(released_days(2022, utc(6, 1, 0, 0)), 1..26);
+        assert_eq!(released_days(2024, utc(6, 1, 0, 0)), 1..1);
+        assert_eq!(
+            released_days(2025, Utc.with_ymd_and_hms(2025, 12, 31, 0, 0, 0).unwrap()),
+            1..13
+        );
+
+        assert!(is_released(2023, 11, utc(12, 12, 4, 59)));
+        assert!(!is_released(2023, 12, utc(12, 12, 4, 59)));
+    }
+
+    #[test]
+    fn next() {
+        assert_eq!(
+            next_unlock(2023, utc(12, 12, 4, 59)),
+            Some((12, utc(12, 12, 5, 0)))
+        );
+        assert_eq!(
+            next_unlock(2023, utc(12, 12, 5, 0)),
+            Some((13, utc(12, 13, 5, 0)))
+        );
+        assert_eq!(next_unlock(2023, utc(12, 25, 5, 0)), None);
+    }
+}